@@ -101,6 +101,50 @@
 //!     .returning(|x, y| x + y);
 //! ```
 //!
+//! If the return type implements `Default`, then
+//! [`return_default`](examples::__mock_MockFoo_Foo::__foo::Expectation::return_default)
+//! will return its default value.  Unlike the automatic default return values,
+//! this works without the **nightly** feature.
+//!
+//! ```
+//! # use mockall::*;
+//! #[automock]
+//! trait MyTrait {
+//!     fn foo(&self) -> Vec<u32>;
+//! }
+//!
+//! let mut mock = MockMyTrait::new();
+//! mock.expect_foo()
+//!     .return_default();
+//! assert!(mock.foo().is_empty());
+//! ```
+//!
+//! Methods that return a `Result` can use
+//! [`return_ok`](examples::__mock_MockFoo_Foo::__foo::Expectation::return_ok)
+//! and
+//! [`return_err`](examples::__mock_MockFoo_Foo::__foo::Expectation::return_err),
+//! or their `_once` variants for values that aren't `Clone`.
+//!
+//! To return a different value on each call, supply them all at once with
+//! [`return_values`](examples::__mock_MockFoo_Foo::__foo::Expectation::return_values).
+//! The mock method will panic if it runs out of values.
+//!
+//! ```
+//! # use mockall::*;
+//! #[automock]
+//! trait MyTrait {
+//!     fn foo(&self) -> u32;
+//! }
+//!
+//! let mut mock = MockMyTrait::new();
+//! mock.expect_foo()
+//!     .times(3)
+//!     .return_values(vec![1, 2, 3]);
+//! assert_eq!(1, mock.foo());
+//! assert_eq!(2, mock.foo());
+//! assert_eq!(3, mock.foo());
+//! ```
+//!
 //! Additionally, constants that aren't `Clone` can be returned with the
 //! [`return_once`](examples::__mock_MockFoo_Foo::__foo::Expectation::return_once)
 //! method.
@@ -202,7 +246,11 @@
 //! ```
 //!
 //! See [`predicate`] for a list of Mockall's builtin predicate functions.
-//! For convenience,
+//! Besides the usual comparisons like [`eq`](predicate::eq) and
+//! [`lt`](predicate::lt), it includes [`in_range`](predicate::in_range) for
+//! checking that an argument lies within a range, and
+//! [`Captor`](predicate::Captor), which matches anything but saves the
+//! arguments so they can be checked after the fact.  For convenience,
 //! [`withf`](examples::__mock_MockFoo_Foo::__foo::Expectation::withf)
 //! is a shorthand for setting the commonly used
 //! [`function`] predicate.  The arguments to the predicate function are the
@@ -225,6 +273,27 @@
 //! # }
 //! ```
 //!
+//! Predicates can be combined with the [`and`](PredicateBooleanExt::and),
+//! [`or`](PredicateBooleanExt::or), and [`not`](PredicateBooleanExt::not)
+//! methods of [`PredicateBooleanExt`].  For example:
+//!
+//! ```should_panic
+//! # use mockall::*;
+//! # use mockall::predicate::*;
+//! #[automock]
+//! trait Foo {
+//!     fn foo(&self, x: u32);
+//! }
+//!
+//! let mut mock = MockFoo::new();
+//! mock.expect_foo()
+//!     .with(gt(0).and(lt(10)).and(eq(5).not()))
+//!     .return_const(());
+//!
+//! mock.foo(7);    // Ok
+//! mock.foo(5);    // Panics!
+//! ```
+//!
 //! ### Matching multiple calls
 //!
 //! Matchers can also be used to discriminate between different invocations of
@@ -273,6 +342,31 @@
 //!     .return_const(None);
 //! ```
 //!
+//! If the expectations can't conveniently be set in that order, for example
+//! because a fallback is set by shared setup code, then give the more specific
+//! ones a higher `priority`.  The highest priority matching expectation is
+//! used, regardless of order.  The default priority is 0.
+//!
+//! ```
+//! # use mockall::*;
+//! # use mockall::predicate::*;
+//! #[automock]
+//! trait Foo {
+//!     fn foo(&self, x: u32) -> u32;
+//! }
+//!
+//! let mut mock = MockFoo::new();
+//! mock.expect_foo()
+//!     .with(always())
+//!     .return_const(0u32);
+//! mock.expect_foo()
+//!     .with(eq(3))
+//!     .priority(1)
+//!     .return_const(30u32);
+//! assert_eq!(30, mock.foo(3));
+//! assert_eq!(0, mock.foo(4));
+//! ```
+//!
 //! ## Call counts
 //!
 //! By default, every expectation is allowed to be called an unlimited number of
@@ -296,6 +390,11 @@
 //! mock.foo(1);    // Panics!
 //! ```
 //!
+//! Expectations that haven't been called often enough are reported when the
+//! mock object drops, or at the next [checkpoint](#checkpoints).  If several
+//! expectations are unsatisfied, even for different methods, the panic message
+//! lists every one of them.
+//!
 //! See also
 //! [`never`](examples::__mock_MockFoo_Foo::__foo::Expectation::never) and
 //! [`times`](examples::__mock_MockFoo_Foo::__foo::Expectation::times).
@@ -558,6 +657,36 @@
 //! # }
 //! ```
 //!
+//! For this or any other method that returns a `Box<dyn Trait>`, the
+//! expectation also has a `returning_boxed` method, which boxes the closure's
+//! return value automatically.  That's handy for returning fakes, or even other
+//! mock objects.
+//!
+//! ```
+//! # use mockall::*;
+//! #[automock]
+//! trait Handler {
+//!     fn handle(&self, x: u32) -> u32;
+//! }
+//!
+//! #[automock]
+//! trait Factory {
+//!     fn handler(&self) -> Box<dyn Handler + Send>;
+//! }
+//!
+//! # fn main() {
+//! let mut mock = MockFactory::new();
+//! mock.expect_handler()
+//!     .returning_boxed(|| {
+//!         let mut handler = MockHandler::new();
+//!         handler.expect_handle()
+//!             .returning(|x| x + 1);
+//!         handler
+//!     });
+//! assert_eq!(5, mock.handler().handle(4));
+//! # }
+//! ```
+//!
 //! However, `impl Trait` isn't *exactly* equivalent to `Box<dyn Trait>` but
 //! with fewer allocations.  There are some things the former can do but the
 //! latter can't.  For one thing, you can't build a trait object out of a
@@ -594,6 +723,10 @@
 //! those would be to refactor them to return named (but possibly opaque) types
 //! instead.
 //!
+//! Trait methods may return `impl Trait` too, since Rust 1.75.0.  Mockall
+//! mocks them the same way, so the trait's expectations also return `Box<dyn
+//! Trait>`.
+//!
 //! See Also [`impl-trait-for-returning-complex-types-with-ease.html`](https://rust-lang-nursery.github.io/edition-guide/rust-2018/trait-system/impl-trait-for-returning-complex-types-with-ease)
 //!
 //! ### impl Future
@@ -624,6 +757,25 @@
 //! # }
 //! ```
 //!
+//! Or let Mockall do the pinning, with `return_future` or `returning_async`:
+//!
+//! ```
+//! # use mockall::*;
+//! # use futures::{Future, future};
+//! # struct Foo {}
+//! # #[automock]
+//! # impl Foo {
+//! #     fn foo(&self, x: i32) -> impl Future<Output=i32> {
+//! #         future::ready(x)
+//! #     }
+//! # }
+//! # fn main() {
+//! let mut mock = MockFoo::new();
+//! mock.expect_foo()
+//!     .returning_async(|x| async move { x + 1 });
+//! # }
+//! ```
+//!
 //! ## Mocking structs
 //!
 //! Mockall mocks structs as well as traits.  The problem here is a namespace
@@ -1133,14 +1285,23 @@
 use downcast::*;
 use std::{
     any,
+    cell::RefCell,
     fmt::{self, Debug, Formatter},
+    future::Future,
     marker::PhantomData,
     ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
           RangeToInclusive},
+    pin::Pin,
     sync::{
         Arc,
+        Mutex,
+        MutexGuard,
+        PoisonError,
         atomic::{AtomicUsize, Ordering}
     },
+    task::{Context, Poll, Waker},
+    thread,
+    time::{Duration, Instant},
 };
 
 #[doc(hidden)]
@@ -1156,7 +1317,6 @@ pub use predicates::{
     boolean::PredicateBooleanExt,
     prelude::{
         Predicate, PredicateBoxExt, PredicateFileContentExt, PredicateStrExt,
-        predicate
     }
 };
 #[doc(hidden)]
@@ -1166,6 +1326,7 @@ pub use predicates_tree::CaseTreeExt;
 extern crate self as mockall;
 #[cfg(doc)]
 pub mod examples;
+pub mod predicate;
 
 /// Automatically generate mock types for structs and traits.
 ///
@@ -1232,6 +1393,18 @@ pub mod examples;
 /// }
 /// ```
 ///
+/// A trait's mock can be given a name other than "MockFoo" with a `struct`
+/// metaitem:
+/// ```
+/// # use mockall_derive::*;
+/// #[automock(struct FooDouble;)]
+/// trait Foo {
+///     fn foo(&self) -> u32;
+/// }
+///
+/// let mock = FooDouble::new();
+/// ```
+///
 /// Finally, `#[automock]` can also mock foreign functions.  This requires
 /// another metaitem to specify the mock module name.
 ///
@@ -1253,10 +1426,124 @@ pub mod examples;
 ///   structs that implement traits.
 /// * Mocking a struct or trait defined in another crate.
 /// * Mocking a trait with trait bounds.
-/// * If the autogenerated "MockFoo" name isn't acceptable, and you want
-///   to choose your own name for the mock structure.
+/// * If the autogenerated "MockFoo" name isn't acceptable for a struct, and
+///   you want to choose your own name for the mock structure.
 pub use mockall_derive::automock;
 
+/// Keep a trait method's default implementation instead of mocking it.
+///
+/// When `#[automock]` is applied to a trait, every method gets mocked, even
+/// those with default implementations.  Mark a method with `#[use_default]` to
+/// make the mock object inherit its default implementation instead.  The mock
+/// object won't have an `expect_` method for it.  This is handy when a default
+/// method only calls other trait methods.
+///
+/// If Mockall is only a dev-dependency, apply the attribute conditionally
+/// along with `#[automock]`, like `#[cfg_attr(test, use_default)]`.  See
+/// [`skip`](macro@skip) for an example.
+///
+/// # Examples
+///
+/// ```
+/// # use mockall::*;
+/// #[automock]
+/// trait Foo {
+///     fn foo(&self) -> u32;
+///     #[use_default]
+///     fn double_foo(&self) -> u32 {
+///         2 * self.foo()
+///     }
+/// }
+///
+/// let mut mock = MockFoo::new();
+/// mock.expect_foo()
+///     .return_const(21u32);
+/// assert_eq!(42, mock.double_foo());
+/// ```
+pub use mockall_derive::use_default;
+
+/// Don't mock a trait method at all.
+///
+/// Some methods can't be mocked, for example because their signatures use
+/// features that Mockall doesn't support.  Mark such a method with `#[skip]`,
+/// and `#[automock]` will implement it with a stub that panics, leaving the
+/// rest of the trait mockable.  The mock object won't have an `expect_` method
+/// for it.
+///
+/// `#[skip]` is only supported on trait definitions, not on methods of an
+/// `impl` block.
+///
+/// # Examples
+///
+/// ```should_panic
+/// # use mockall::*;
+/// #[automock]
+/// trait Foo {
+///     fn foo(&self) -> u32;
+///     #[skip]
+///     fn bar(&self, x: impl std::fmt::Display);
+/// }
+///
+/// let mut mock = MockFoo::new();
+/// mock.expect_foo()
+///     .return_const(42u32);
+/// assert_eq!(42, mock.foo());
+/// mock.bar(5);    // Panics!
+/// ```
+///
+/// When Mockall is only a dev-dependency and the trait uses
+/// `#[cfg_attr(test, automock)]`, the method's attribute must be conditional
+/// too, or the non-test build won't find it.  `#[automock]` still recognizes
+/// it within `cfg_attr`.
+/// ```
+/// #[cfg(test)]
+/// use mockall::automock;
+///
+/// #[cfg_attr(test, automock)]
+/// trait Foo {
+///     fn foo(&self) -> u32;
+///     #[cfg_attr(test, mockall::skip)]
+///     fn bar(&self, x: impl std::fmt::Display);
+/// }
+/// ```
+pub use mockall_derive::skip;
+
+/// Mock a generic method by turning its generic arguments into trait objects.
+///
+/// Ordinarily, a generic method's expectations are generic too, and must be
+/// set with a turbofish for each type the method will be called with.  But a
+/// method marked `#[concretize]` gets a single non-generic expectation, whose
+/// arguments are `&dyn Trait` instead.  This also works for `impl Trait`
+/// arguments, which Mockall can't otherwise mock.  Arguments of type `T`, `&T`,
+/// `&mut T` and `impl Trait` are converted, and unlike with other generic
+/// methods, the types needn't be `'static`.  Of course, their bounds must be
+/// object safe.  It's an error to use a converted type anywhere else in the
+/// signature, such as in `Vec<T>` or the return type.
+///
+/// Since the arguments may be non-`'static` trait objects, match them with
+/// `withf`, which takes a closure.
+///
+/// Like the other method attributes, `#[concretize]` may be applied with
+/// `cfg_attr`, as in `#[cfg_attr(test, concretize)]`, when `#[automock]` is.
+///
+/// # Examples
+///
+/// ```
+/// # use mockall::*;
+/// #[automock]
+/// trait Logger {
+///     #[concretize]
+///     fn log(&self, msg: impl std::fmt::Display);
+/// }
+///
+/// let mut mock = MockLogger::new();
+/// mock.expect_log()
+///     .withf(|msg| msg.to_string() == "hello")
+///     .return_const(());
+/// mock.log("hello");
+/// ```
+pub use mockall_derive::concretize;
+
 /// Manually mock a structure.
 ///
 /// Sometimes `automock` can't be used.  In those cases you can use `mock!`,
@@ -1331,7 +1618,10 @@ pub use mockall_derive::automock;
 pub use mockall_derive::mock;
 
 #[doc(hidden)]
-pub trait AnyExpectations : Any + Send + Sync {}
+pub trait AnyExpectations : Any + Send + Sync {
+    /// Describe every expectation that hasn't been satisfied yet
+    fn unsatisfied(&self) -> Vec<String>;
+}
 downcast!(dyn AnyExpectations);
 
 #[doc(hidden)]
@@ -1403,7 +1693,28 @@ pub struct MaybeDebugger<'a, T>(pub &'a T);
     }
 }
 
-// Though it's not entirely correct, we treat usize::max_value() as
+/// Lets the generated `return_ok` and `return_err` methods construct any
+/// `Result`, including through type aliases like `io::Result<T>`.
+#[doc(hidden)]
+pub trait ResultLike {
+    type Ok;
+    type Err;
+    fn from_ok(o: Self::Ok) -> Self;
+    fn from_err(e: Self::Err) -> Self;
+}
+
+impl<T, E> ResultLike for Result<T, E> {
+    type Ok = T;
+    type Err = E;
+    fn from_ok(o: T) -> Self {
+        Ok(o)
+    }
+    fn from_err(e: E) -> Self {
+        Err(e)
+    }
+}
+
+// Though it's not entirely correct, we treat usize::MAX as
 // approximately infinity.
 #[derive(Debug)]
 #[doc(hidden)]
@@ -1412,7 +1723,7 @@ pub struct TimesRange(Range<usize>);
 impl Default for TimesRange {
     fn default() -> TimesRange {
         // By default, allow any number of calls
-        TimesRange(0..usize::max_value())
+        TimesRange(0..usize::MAX)
     }
 }
 
@@ -1431,13 +1742,13 @@ impl From<Range<usize>> for TimesRange {
 
 impl From<RangeFrom<usize>> for TimesRange {
     fn from(r: RangeFrom<usize>) -> TimesRange {
-        TimesRange(r.start..usize::max_value())
+        TimesRange(r.start..usize::MAX)
     }
 }
 
 impl From<RangeFull> for TimesRange {
     fn from(_: RangeFull) -> TimesRange {
-        TimesRange(0..usize::max_value())
+        TimesRange(0..usize::MAX)
     }
 }
 
@@ -1488,7 +1799,7 @@ impl Times {
     }
 
     pub fn any(&mut self) {
-        self.range.0 = 0..usize::max_value();
+        self.range.0 = 0..usize::MAX;
     }
 
     /// Return how many times this expectation has been called
@@ -1539,6 +1850,141 @@ impl Times {
     }
 }
 
+thread_local! {
+    static PENDING_CAPTURES: RefCell<Vec<Box<dyn FnOnce()>>> =
+        RefCell::new(Vec::new());
+}
+
+/// Values saved by [`Captor`](predicate::Captor)s while checking whether an
+/// expectation matches a call.  They're only kept if that expectation goes on
+/// to handle the call.
+#[doc(hidden)]
+pub struct PendingCaptures;
+
+#[doc(hidden)]
+impl PendingCaptures {
+    /// Save a capture, to be kept if the current expectation is used.
+    pub(crate) fn push(f: Box<dyn FnOnce()>) {
+        PENDING_CAPTURES.with(|pc| pc.borrow_mut().push(f));
+    }
+
+    /// Forget captures made while checking a previous expectation.
+    pub fn discard() {
+        PENDING_CAPTURES.with(|pc| pc.borrow_mut().clear());
+    }
+
+    /// The current expectation is handling the call, so keep its captures.
+    pub fn commit() {
+        let captures = PENDING_CAPTURES.with(|pc| pc.replace(Vec::new()));
+        for f in captures {
+            f();
+        }
+    }
+}
+
+thread_local! {
+    static PENDING_DELAYS: RefCell<Vec<Option<Duration>>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Delays set by `with_delay`.  The mock method sleeps only after it has
+/// released any locks, so concurrent callers of a static method don't wait for
+/// each other.  It's a stack, because a return closure may itself call another
+/// mock method.
+#[doc(hidden)]
+pub struct PendingDelays;
+
+#[doc(hidden)]
+impl PendingDelays {
+    /// Make room for the delay of a mock method call that's starting.  The
+    /// returned guard sleeps when dropped, even if the call panics.
+    pub fn enter() -> DelayGuard {
+        let depth = PENDING_DELAYS.with(|pd| {
+            let mut pd = pd.borrow_mut();
+            pd.push(None);
+            pd.len() - 1
+        });
+        DelayGuard { depth }
+    }
+
+    /// Remember the delay of the expectation that's handling the current call.
+    pub fn set(delay: Option<Duration>) {
+        PENDING_DELAYS.with(|pd| {
+            if let Some(slot) = pd.borrow_mut().last_mut() {
+                *slot = delay;
+            }
+        });
+    }
+}
+
+/// Sleeps for the delay of one mock method call, once that call is finished.
+#[doc(hidden)]
+pub struct DelayGuard {
+    depth: usize
+}
+
+impl Drop for DelayGuard {
+    fn drop(&mut self) {
+        let delay = PENDING_DELAYS.with(|pd| {
+            let mut pd = pd.borrow_mut();
+            let delay = pd.get(self.depth).copied().flatten();
+            // Also discard the slots of any nested calls that didn't finish
+            pd.truncate(self.depth);
+            delay
+        });
+        if let Some(delay) = delay {
+            thread::sleep(delay);
+        }
+    }
+}
+
+/// A future that waits until a deadline before polling the inner future.  Used
+/// by `with_async_delay`.
+///
+/// It doesn't depend on any particular async runtime: the first time that it's
+/// polled too early, it spawns a thread to wake the task at the deadline.
+#[doc(hidden)]
+pub struct Delayed<F> {
+    deadline: Instant,
+    inner: F,
+    waker: Option<Arc<Mutex<Waker>>>,
+}
+
+#[doc(hidden)]
+impl<F> Delayed<F> {
+    pub fn new(delay: Duration, inner: F) -> Self {
+        Delayed {
+            deadline: Instant::now() + delay,
+            inner,
+            waker: None
+        }
+    }
+}
+
+impl<F: Future + Unpin> Future for Delayed<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        let now = Instant::now();
+        if now >= self.deadline {
+            return Pin::new(&mut self.inner).poll(cx);
+        }
+        if let Some(waker) = &self.waker {
+            *waker.lock().unwrap() = cx.waker().clone();
+        } else {
+            let waker = Arc::new(Mutex::new(cx.waker().clone()));
+            let timer_waker = waker.clone();
+            let remaining = self.deadline - now;
+            thread::spawn(move || {
+                thread::sleep(remaining);
+                timer_waker.lock().unwrap().wake_by_ref();
+            });
+            self.waker = Some(waker);
+        }
+        Poll::Pending
+    }
+}
+
 /// Non-generic keys to `GenericExpectation` internal storage
 #[doc(hidden)]
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -1656,3 +2102,94 @@ impl Sequence {
         handle
     }
 }
+
+/// State shared between the return closures of several expectations.
+///
+/// A `SharedState` is a cheaply cloneable handle to a single value.  Move a clone
+/// into each `returning` closure that needs it, and they can all read and
+/// update the same scripted state, without every test writing its own
+/// `Arc<Mutex<T>>`.
+///
+/// # Examples
+/// ```
+/// # use mockall::*;
+/// #[automock]
+/// trait Stack {
+///     fn push(&self, x: u32);
+///     fn pop(&self) -> Option<u32>;
+///     fn len(&self) -> usize;
+/// }
+///
+/// let state = SharedState::new(Vec::new());
+/// let mut mock = MockStack::new();
+/// let s = state.clone();
+/// mock.expect_push()
+///     .returning(move |x| s.update(|v| v.push(x)));
+/// let s = state.clone();
+/// mock.expect_pop()
+///     .returning(move || s.update(|v| v.pop()));
+/// let s = state.clone();
+/// mock.expect_len()
+///     .returning(move || s.update(|v| v.len()));
+///
+/// mock.push(1);
+/// mock.push(2);
+/// assert_eq!(2, mock.len());
+/// assert_eq!(Some(2), mock.pop());
+/// assert_eq!(vec![1], state.get());
+/// ```
+pub struct SharedState<T> {
+    inner: Arc<Mutex<T>>
+}
+
+impl<T> SharedState<T> {
+    /// Create a new `SharedState` holding `value`.
+    pub fn new(value: T) -> Self {
+        SharedState { inner: Arc::new(Mutex::new(value)) }
+    }
+
+    /// Return a copy of the current value.
+    pub fn get(&self) -> T where T: Clone {
+        self.lock().clone()
+    }
+
+    /// Replace the current value.
+    pub fn set(&self, value: T) {
+        *self.lock() = value;
+    }
+
+    /// Run `f` with exclusive access to the value, and return its result.
+    ///
+    /// Don't use the same `SharedState` from within `f`, or it will deadlock.
+    pub fn update<F, R>(&self, f: F) -> R
+        where F: FnOnce(&mut T) -> R
+    {
+        f(&mut self.lock())
+    }
+
+    // A closure that panicked while holding the lock has already failed the
+    // test, so don't let it cascade into every later access.
+    fn lock(&self) -> MutexGuard<'_, T> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Clone for SharedState<T> {
+    fn clone(&self) -> Self {
+        SharedState { inner: self.inner.clone() }
+    }
+}
+
+impl<T: Debug> Debug for SharedState<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedState")
+            .field(&*self.lock())
+            .finish()
+    }
+}
+
+impl<T: Default> Default for SharedState<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
@@ -0,0 +1,417 @@
+// vim: tw=80
+//! Predicate factories
+//!
+//! These are the predicates from the
+//! [`predicates`](https://docs.rs/predicates) crate, plus a few extras that
+//! are especially handy for matching mock method arguments.
+use predicates::reflection::PredicateReflection;
+use std::{
+    borrow::ToOwned,
+    fmt::{self, Debug, Display, Formatter},
+    ops::{Mul, RangeBounds, Sub},
+    sync::{Arc, Mutex},
+};
+
+pub use predicates::prelude::predicate::*;
+
+use crate::Predicate;
+
+/// Predicate that checks whether a value lies within a range.
+///
+/// This is created by [`in_range`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InRangePredicate<R> {
+    range: R
+}
+
+impl<R, T> Predicate<T> for InRangePredicate<R>
+    where R: RangeBounds<T> + Debug,
+          T: PartialOrd + ?Sized
+{
+    fn eval(&self, variable: &T) -> bool {
+        self.range.contains(variable)
+    }
+}
+
+impl<R: Debug> PredicateReflection for InRangePredicate<R> {}
+
+impl<R: Debug> Display for InRangePredicate<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "var in {:?}", self.range)
+    }
+}
+
+/// Creates a new predicate that will return `true` when the given `variable`
+/// lies within `range`.
+///
+/// Any of Rust's range types may be used, whether bounded or unbounded,
+/// inclusive or exclusive.
+///
+/// # Examples
+///
+/// ```
+/// use mockall::*;
+/// use mockall::predicate::*;
+///
+/// let predicate_fn = predicate::in_range(1..5);
+/// assert!(predicate_fn.eval(&1));
+/// assert!(!predicate_fn.eval(&5));
+///
+/// let predicate_fn = predicate::in_range(..=0.5);
+/// assert!(predicate_fn.eval(&0.5));
+/// assert!(!predicate_fn.eval(&0.75));
+/// ```
+pub fn in_range<R>(range: R) -> InRangePredicate<R> {
+    InRangePredicate { range }
+}
+
+/// Predicate that checks whether a floating point value is close to an
+/// expected value.
+///
+/// This is created by [`float_eq`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatEqPredicate<F> {
+    expected: F,
+    epsilon: F,
+    relative: bool
+}
+
+impl<F> FloatEqPredicate<F> {
+    /// Compare the relative error instead of the absolute error.  The value
+    /// will match if its difference from the expected value is no more than
+    /// `epsilon` times the larger of their magnitudes.
+    pub fn relative(self) -> Self {
+        FloatEqPredicate { relative: true, ..self }
+    }
+}
+
+impl<F> Predicate<F> for FloatEqPredicate<F>
+    where F: Copy + Debug + Default + PartialOrd + Sub<Output=F> + Mul<Output=F>
+{
+    fn eval(&self, variable: &F) -> bool {
+        let abs = |x: F| if x < F::default() { F::default() - x } else { x };
+        let tolerance = if self.relative {
+            let (a, b) = (abs(*variable), abs(self.expected));
+            self.epsilon * if a > b { a } else { b }
+        } else {
+            self.epsilon
+        };
+        // Check for equality first, so infinities can match
+        *variable == self.expected ||
+            abs(*variable - self.expected) <= tolerance
+    }
+}
+
+impl<F: Debug> PredicateReflection for FloatEqPredicate<F> {}
+
+impl<F: Debug> Display for FloatEqPredicate<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.relative {
+            write!(f, "var == {:?} +/- {:?} relative", self.expected,
+                self.epsilon)
+        } else {
+            write!(f, "var == {:?} +/- {:?}", self.expected, self.epsilon)
+        }
+    }
+}
+
+/// Creates a new predicate that will return `true` when the given `variable`
+/// differs from `expected` by no more than `epsilon`.
+///
+/// Exact equality is rarely useful for floating point arguments.  This works
+/// for both `f32` and `f64`.  Use [`FloatEqPredicate::relative`] to compare
+/// the relative error instead, and
+/// [`float::is_close`](predicates::float::is_close) to compare ULPs.  `NaN`
+/// never matches.
+///
+/// # Examples
+///
+/// ```
+/// use mockall::*;
+/// use mockall::predicate::*;
+///
+/// let predicate_fn = predicate::float_eq(1.0f32, 0.01);
+/// assert!(predicate_fn.eval(&1.005));
+/// assert!(!predicate_fn.eval(&1.02));
+///
+/// let predicate_fn = predicate::float_eq(1000.0f64, 0.01).relative();
+/// assert!(predicate_fn.eval(&1005.0));
+/// assert!(!predicate_fn.eval(&1020.0));
+/// ```
+pub fn float_eq<F>(expected: F, epsilon: F) -> FloatEqPredicate<F> {
+    FloatEqPredicate { expected, epsilon, relative: false }
+}
+
+/// Predicate that checks whether a collection contains a value.
+///
+/// This is created by [`contains`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContainsPredicate<T> {
+    value: T
+}
+
+impl<C, T> Predicate<C> for ContainsPredicate<T>
+    where C: ?Sized,
+          for<'a> &'a C: IntoIterator<Item=&'a T>,
+          T: Debug + PartialEq
+{
+    fn eval(&self, variable: &C) -> bool {
+        variable.into_iter().any(|x| *x == self.value)
+    }
+}
+
+impl<T: Debug> PredicateReflection for ContainsPredicate<T> {}
+
+impl<T: Debug> Display for ContainsPredicate<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "var contains {:?}", self.value)
+    }
+}
+
+/// Creates a new predicate that will return `true` when the given collection
+/// contains `value`.
+///
+/// The collection may be a slice, a `Vec`, a set, or anything else whose
+/// references can be iterated over.
+///
+/// # Examples
+///
+/// ```
+/// use mockall::*;
+/// use mockall::predicate::*;
+///
+/// let predicate_fn = predicate::contains(3);
+/// assert!(predicate_fn.eval(&vec![1, 2, 3]));
+/// assert!(!predicate_fn.eval(&[4, 5][..]));
+/// ```
+pub fn contains<T>(value: T) -> ContainsPredicate<T> {
+    ContainsPredicate { value }
+}
+
+/// Predicate that checks a collection's length.
+///
+/// This is created by [`has_len`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HasLenPredicate {
+    len: usize
+}
+
+impl<C> Predicate<C> for HasLenPredicate
+    where C: ?Sized,
+          for<'a> &'a C: IntoIterator
+{
+    fn eval(&self, variable: &C) -> bool {
+        variable.into_iter().count() == self.len
+    }
+}
+
+impl PredicateReflection for HasLenPredicate {}
+
+impl Display for HasLenPredicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "var.len() == {}", self.len)
+    }
+}
+
+/// Creates a new predicate that will return `true` when the given collection
+/// has exactly `len` elements.
+///
+/// # Examples
+///
+/// ```
+/// use mockall::*;
+/// use mockall::predicate::*;
+///
+/// let predicate_fn = predicate::has_len(2);
+/// assert!(predicate_fn.eval(&vec![1, 2]));
+/// assert!(!predicate_fn.eval(&[1, 2, 3][..]));
+/// ```
+pub fn has_len(len: usize) -> HasLenPredicate {
+    HasLenPredicate { len }
+}
+
+/// Predicate that checks whether every element of a collection belongs to
+/// another collection.
+///
+/// This is created by [`is_subset_of`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IsSubsetPredicate<T> {
+    superset: Vec<T>
+}
+
+impl<C, T> Predicate<C> for IsSubsetPredicate<T>
+    where C: ?Sized,
+          for<'a> &'a C: IntoIterator<Item=&'a T>,
+          T: Debug + PartialEq
+{
+    fn eval(&self, variable: &C) -> bool {
+        variable.into_iter().all(|x| self.superset.contains(x))
+    }
+}
+
+impl<T: Debug> PredicateReflection for IsSubsetPredicate<T> {}
+
+impl<T: Debug> Display for IsSubsetPredicate<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "var is a subset of {:?}", self.superset)
+    }
+}
+
+/// Creates a new predicate that will return `true` when every element of the
+/// given collection is also an element of `superset`.
+///
+/// # Examples
+///
+/// ```
+/// use mockall::*;
+/// use mockall::predicate::*;
+///
+/// let predicate_fn = predicate::is_subset_of([1, 2, 3]);
+/// assert!(predicate_fn.eval(&vec![3, 1]));
+/// assert!(!predicate_fn.eval(&vec![1, 4]));
+/// ```
+pub fn is_subset_of<I, T>(superset: I) -> IsSubsetPredicate<T>
+    where I: IntoIterator<Item=T>
+{
+    IsSubsetPredicate { superset: superset.into_iter().collect() }
+}
+
+/// Predicate that checks whether a collection has the same elements as
+/// another, in any order.
+///
+/// This is created by [`unordered_eq`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnorderedEqPredicate<T> {
+    expected: Vec<T>
+}
+
+impl<C, T> Predicate<C> for UnorderedEqPredicate<T>
+    where C: ?Sized,
+          for<'a> &'a C: IntoIterator<Item=&'a T>,
+          T: Debug + PartialEq
+{
+    fn eval(&self, variable: &C) -> bool {
+        let mut unmatched = self.expected.iter().collect::<Vec<_>>();
+        for x in variable {
+            match unmatched.iter().position(|e| *e == x) {
+                Some(i) => {
+                    unmatched.swap_remove(i);
+                },
+                None => return false
+            }
+        }
+        unmatched.is_empty()
+    }
+}
+
+impl<T: Debug> PredicateReflection for UnorderedEqPredicate<T> {}
+
+impl<T: Debug> Display for UnorderedEqPredicate<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "var == {:?} in any order", self.expected)
+    }
+}
+
+/// Creates a new predicate that will return `true` when the given collection
+/// has the same elements as `expected`, with the same multiplicities, but in
+/// any order.
+///
+/// # Examples
+///
+/// ```
+/// use mockall::*;
+/// use mockall::predicate::*;
+///
+/// let predicate_fn = predicate::unordered_eq(vec![1, 2, 2]);
+/// assert!(predicate_fn.eval(&vec![2, 1, 2]));
+/// assert!(!predicate_fn.eval(&vec![1, 2]));
+/// assert!(!predicate_fn.eval(&vec![1, 1, 2]));
+/// ```
+pub fn unordered_eq<I, T>(expected: I) -> UnorderedEqPredicate<T>
+    where I: IntoIterator<Item=T>
+{
+    UnorderedEqPredicate { expected: expected.into_iter().collect() }
+}
+
+/// Predicate that matches any value, and saves a copy of it for later.
+///
+/// Use a `Captor` to make assertions about a mock method's arguments after the
+/// code under test has run, instead of writing them all as predicates.  Clones
+/// of a `Captor` share the same storage, so pass a clone to
+/// [`with`](crate::examples::__mock_MockFoo_Foo::__foo::Expectation::with)
+/// and keep the original.
+///
+/// Only calls that the `Expectation` actually handles are captured.  Calls that
+/// fail its other predicates, or that go to another expectation because this
+/// one has already been called enough times, are not.
+///
+/// # Examples
+///
+/// ```
+/// use mockall::*;
+/// use mockall::predicate::*;
+///
+/// #[automock]
+/// trait Foo {
+///     fn foo(&self, name: &str);
+/// }
+///
+/// let captor = Captor::new();
+/// let mut mock = MockFoo::new();
+/// mock.expect_foo()
+///     .with(captor.clone())
+///     .return_const(());
+/// mock.foo("one");
+/// mock.foo("two");
+/// assert_eq!(captor.values(), ["one", "two"]);
+/// ```
+#[derive(Debug)]
+pub struct Captor<T> {
+    values: Arc<Mutex<Vec<T>>>
+}
+
+impl<T> Captor<T> {
+    /// Create a new `Captor`, which hasn't captured anything yet.
+    pub fn new() -> Self {
+        Captor { values: Arc::new(Mutex::new(Vec::new())) }
+    }
+
+    /// Return all of the values captured so far, oldest first.
+    pub fn values(&self) -> Vec<T> where T: Clone {
+        self.values.lock().unwrap().clone()
+    }
+}
+
+impl<T> Clone for Captor<T> {
+    fn clone(&self) -> Self {
+        Captor { values: self.values.clone() }
+    }
+}
+
+impl<T> Default for Captor<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P, T> Predicate<P> for Captor<T>
+    where P: ToOwned<Owned=T> + ?Sized,
+          T: 'static
+{
+    fn eval(&self, variable: &P) -> bool {
+        let values = self.values.clone();
+        let value = variable.to_owned();
+        crate::PendingCaptures::push(Box::new(move || {
+            values.lock().unwrap().push(value)
+        }));
+        true
+    }
+}
+
+impl<T> PredicateReflection for Captor<T> {}
+
+impl<T> Display for Captor<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "captor")
+    }
+}
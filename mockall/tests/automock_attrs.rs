@@ -1,6 +1,8 @@
 // vim: tw=80
 //! Attributes are applied to the mock object, too.
 #![deny(warnings)]
+// multics is deliberately not a real target_os
+#![allow(unexpected_cfgs)]
 
 use mockall::*;
 
@@ -0,0 +1,44 @@
+// vim: tw=80
+//! Mockall's method attributes work within cfg_attr, for crates that use
+//! mockall only as a dev-dependency
+#![deny(warnings)]
+
+#[cfg(test)]
+use mockall::*;
+
+#[cfg_attr(test, automock)]
+trait Foo {
+    fn foo(&self, x: u32) -> u32;
+    #[cfg_attr(test, mockall::skip)]
+    fn bar(&self, x: impl AsRef<str>) -> u32;
+    #[cfg_attr(test, use_default)]
+    fn baz(&self, x: u32) -> u32 {
+        self.foo(x) + 1
+    }
+    #[cfg_attr(test, mockall::concretize)]
+    fn bean(&self, x: impl AsRef<str>) -> usize;
+}
+
+#[test]
+#[should_panic(expected = "MockFoo::bar is not mocked, because it is marked #[skip]")]
+fn skip() {
+    let mock = MockFoo::new();
+    mock.bar("x");
+}
+
+#[test]
+fn use_default() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .returning(|x| 2 * x);
+    assert_eq!(9, mock.baz(4));
+}
+
+#[test]
+fn concretize() {
+    let mut mock = MockFoo::new();
+    mock.expect_bean()
+        .withf(|x| x.as_ref() == "abc")
+        .returning(|x| x.as_ref().len());
+    assert_eq!(3, mock.bean("abc"));
+}
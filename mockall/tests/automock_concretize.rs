@@ -0,0 +1,102 @@
+// vim: tw=80
+//! Generic methods whose arguments are turned into trait objects
+#![deny(warnings)]
+
+use mockall::*;
+use std::{
+    fmt::{Debug, Display},
+    io::Write
+};
+
+#[automock]
+trait Foo {
+    #[concretize]
+    fn foo<T: Display>(&self, x: T) -> u32;
+    #[concretize]
+    fn bar<T>(&self, x: &T) -> String where T: Debug;
+    #[concretize]
+    fn baz(&self, x: impl AsRef<str>, y: u32) -> usize;
+    #[concretize]
+    fn bean<T: Write>(x: &mut T);
+    #[concretize]
+    fn log(&self, msg: impl Display);
+}
+
+/// A type that isn't 'static
+struct Borrowed<'a>(&'a str);
+
+impl<'a> Display for Borrowed<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self.0, f)
+    }
+}
+
+#[test]
+fn by_value() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .withf(|x| x.to_string() == "42")
+        .return_const(1u32);
+    mock.expect_foo()
+        .withf(|x| x.to_string() == "hello")
+        .return_const(2u32);
+    assert_eq!(1, mock.foo(42));
+    assert_eq!(2, mock.foo("hello"));
+}
+
+#[test]
+fn by_ref() {
+    let mut mock = MockFoo::new();
+    mock.expect_bar()
+        .returning(|x| format!("{:?}", x));
+    assert_eq!("[1, 2]", mock.bar(&vec![1, 2]));
+}
+
+#[test]
+fn impl_trait() {
+    let mut mock = MockFoo::new();
+    mock.expect_baz()
+        .returning(|x, y| x.as_ref().len() + y as usize);
+    assert_eq!(7, mock.baz("abcde", 2));
+    assert_eq!(3, mock.baz(String::from("xyz"), 0));
+}
+
+#[test]
+fn mut_ref() {
+    let ctx = MockFoo::bean_context();
+    ctx.expect()
+        .returning(|x| x.write_all(&[1, 2]).unwrap());
+    let mut v = vec![0];
+    MockFoo::bean(&mut v);
+    assert_eq!(v, [0, 1, 2]);
+}
+
+#[test]
+fn nonstatic() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .withf(|x| x.to_string() == "abc")
+        .return_const(4u32);
+    let s = String::from("abc");
+    assert_eq!(4, mock.foo(Borrowed(&s)));
+}
+
+#[test]
+fn plain_impl_trait() {
+    let mut mock = MockFoo::new();
+    mock.expect_log()
+        .withf(|msg| msg.to_string() == "hello")
+        .times(2)
+        .return_const(());
+    mock.log("hello");
+    mock.log(String::from("hello"));
+}
+
+#[test]
+fn with() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .with(predicate::always())
+        .return_const(3u32);
+    assert_eq!(3, mock.foo(7u8));
+}
@@ -4,6 +4,7 @@
 //! to make, eg., Box, available.
 
 #![no_std]
+#![allow(dead_code)]
 extern crate std;
 
 use mockall::*;
@@ -4,6 +4,7 @@
 //! This is tricky because the Context object has a lifetime parameter, yet the
 //! poll method must not be treated as a generic method.
 #![deny(warnings)]
+#![allow(dead_code)]
 
 use mockall::*;
 use std::{
@@ -26,6 +27,7 @@ impl<T: 'static> Future for Foo<T> {
 }
 
 #[test]
+#[allow(clippy::redundant_async_block)] // The async block is the whole point
 fn ready() {
     let mut mock = MockFoo::<u32>::new();
     mock.expect_poll()
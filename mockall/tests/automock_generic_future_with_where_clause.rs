@@ -5,6 +5,7 @@
 //!
 //! Mockall must not emit the where clause for the method's Expectation.
 #![deny(warnings)]
+#![allow(dead_code)]
 
 use mockall::*;
 //use std::task::Context;
@@ -5,8 +5,9 @@
 //! You need Pin<Box<dyn Future<...>>> instead.
 #![deny(warnings)]
 
-use futures::{Future, FutureExt, Stream, StreamExt, future, stream};
+use futures::{Future, FutureExt, Stream, StreamExt, executor, future, stream};
 use mockall::*;
+use std::time::{Duration, Instant};
 
 pub struct Foo{}
 
@@ -35,12 +36,29 @@ fn returning_future() {
         .unwrap();
 }
 
+#[test]
+fn return_future() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .return_future(future::ready(42));
+    assert_eq!(42, mock.foo().now_or_never().unwrap());
+}
+
+#[test]
+fn returning_async() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .returning_async(|| async { 42 });
+    assert_eq!(42, mock.foo().now_or_never().unwrap());
+    assert_eq!(42, mock.foo().now_or_never().unwrap());
+}
+
 #[test]
 fn returning_stream() {
     let mut mock = MockFoo::new();
     mock.expect_bar()
         .returning(|| {
-            Box::pin(stream::iter(vec![42].into_iter()))
+            Box::pin(stream::iter(vec![42]))
         });
     let all = mock.bar()
         .collect::<Vec<u32>>()
@@ -48,3 +66,18 @@ fn returning_stream() {
         .unwrap();
     assert_eq!(&all[..], &[42][..]);
 }
+
+/// The future waits, but the call itself doesn't block
+#[test]
+fn with_async_delay() {
+    let delay = Duration::from_millis(100);
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .with_async_delay(delay)
+        .returning_async(|| async { 42 });
+    let start = Instant::now();
+    let mut fut = mock.foo();
+    assert!((&mut fut).now_or_never().is_none());
+    assert_eq!(42, executor::block_on(fut));
+    assert!(start.elapsed() >= delay);
+}
@@ -0,0 +1,17 @@
+// vim: tw=80
+//! A trait method that returns "impl Trait"
+#![deny(warnings)]
+
+use mockall::*;
+
+#[automock]
+trait Foo {
+    fn foo(&self) -> impl Iterator<Item=u32>;
+}
+
+#[test]
+fn returning() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo().returning(|| Box::new(vec![1, 2, 3].into_iter()));
+    assert_eq!(vec![1, 2, 3], mock.foo().collect::<Vec<_>>());
+}
@@ -1,6 +1,7 @@
 // vim: tw=80
 //! A struct implements a trait with associated types
 #![deny(warnings)]
+#![allow(dead_code)]
 
 use mockall::*;
 
@@ -5,6 +5,7 @@
 //! This test is designed to work with "--cfg reprocheck"
 
 #![deny(warnings)]
+#![allow(dead_code)]
 #![allow(clippy::needless_lifetimes)]
 
 use mockall::*;
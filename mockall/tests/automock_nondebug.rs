@@ -1,6 +1,7 @@
 // vim: tw=80
 //! A method may have non-Debug arguments and/or return values.
 #![deny(warnings)]
+#![allow(dead_code)]
 
 use mockall::*;
 
@@ -0,0 +1,62 @@
+// vim: tw=80
+//! Methods that return boxed trait objects can use `returning_boxed`
+#![deny(warnings)]
+
+use mockall::*;
+
+#[automock]
+trait Handler {
+    fn handle(&self, x: u32) -> u32;
+}
+
+struct Doubler {}
+
+impl Doubler {
+    fn new() -> Self {
+        Doubler {}
+    }
+}
+
+impl Handler for Doubler {
+    fn handle(&self, x: u32) -> u32 {
+        2 * x
+    }
+}
+
+#[automock]
+trait Factory {
+    fn handler(&self) -> Box<dyn Handler>;
+    fn send_handler(&self, x: u32) -> Box<dyn Handler + Send>;
+    fn static_handler() -> Box<dyn Handler>;
+}
+
+#[test]
+fn constructor() {
+    let mut mock = MockFactory::new();
+    mock.expect_handler()
+        .returning_boxed(Doubler::new);
+    assert_eq!(6, mock.handler().handle(3));
+}
+
+/// A mock object can be nested inside another one's return value
+#[test]
+fn nested_mock() {
+    let mut mock = MockFactory::new();
+    mock.expect_send_handler()
+        .returning_boxed(|x| {
+            let mut handler = MockHandler::new();
+            handler.expect_handle()
+                .returning(move |y| x + y);
+            handler
+        });
+    assert_eq!(5, mock.send_handler(2).handle(3));
+}
+
+#[test]
+fn static_method() {
+    let ctx = MockFactory::static_handler_context();
+    ctx.expect()
+        .times(1)
+        .returning_boxed(Doubler::new);
+    assert_eq!(10, MockFactory::static_handler().handle(5));
+}
@@ -0,0 +1,37 @@
+// vim: tw=80
+//! Other tools' attributes named "skip" must not be mistaken for Mockall's
+#![deny(warnings)]
+
+use mockall::*;
+
+#[automock]
+trait Foo {
+    #[rustfmt::skip]
+    fn foo(&self, x: u32) -> u32;
+}
+
+pub struct Bar {}
+
+#[automock]
+impl Bar {
+    #[rustfmt::skip]
+    pub fn bar(&self, x: u32) -> u32 {
+        x
+    }
+}
+
+#[test]
+fn trait_method() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .returning(|x| x + 1);
+    assert_eq!(5, mock.foo(4));
+}
+
+#[test]
+fn impl_method() {
+    let mut mock = MockBar::new();
+    mock.expect_bar()
+        .returning(|x| x + 1);
+    assert_eq!(5, mock.bar(4));
+}
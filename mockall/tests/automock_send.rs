@@ -1,6 +1,7 @@
 // vim: tw=80
 //! A mock object should be Send
 #![deny(warnings)]
+#![allow(dead_code)]
 
 use mockall::*;
 
@@ -0,0 +1,35 @@
+// vim: tw=80
+//! A trait with a method that can't be mocked, and so is skipped
+#![deny(warnings)]
+
+use mockall::*;
+
+#[automock]
+trait Foo {
+    fn foo(&self, x: u32) -> u32;
+    #[skip]
+    fn bar(&self, x: impl AsRef<str>) -> u32;
+    #[mockall::skip]
+    fn baz() -> u32;
+}
+
+#[test]
+fn mocked() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .returning(|x| x + 1);
+    assert_eq!(5, mock.foo(4));
+}
+
+#[test]
+#[should_panic(expected = "MockFoo::bar is not mocked, because it is marked #[skip]")]
+fn skipped() {
+    let mock = MockFoo::new();
+    mock.bar("x");
+}
+
+#[test]
+#[should_panic(expected = "MockFoo::baz is not mocked, because it is marked #[skip]")]
+fn skipped_static() {
+    MockFoo::baz();
+}
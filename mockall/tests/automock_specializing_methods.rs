@@ -3,6 +3,7 @@
 //! places additional bounds on the struct's generic types via a where
 //! clause.
 #![deny(warnings)]
+#![allow(dead_code)]
 
 use mockall::*;
 
@@ -0,0 +1,27 @@
+// vim: tw=80
+//! automock can choose the mock struct's name
+#![deny(warnings)]
+
+use mockall::*;
+
+#[automock(struct FooDouble;)]
+trait Foo {
+    fn foo(&self) -> u32;
+    fn bar() -> u32;
+}
+
+#[test]
+fn method() {
+    let mut mock = FooDouble::new();
+    mock.expect_foo()
+        .return_const(42u32);
+    assert_eq!(42, mock.foo());
+}
+
+#[test]
+fn static_method() {
+    let ctx = FooDouble::bar_context();
+    ctx.expect()
+        .return_const(42u32);
+    assert_eq!(42, FooDouble::bar());
+}
@@ -0,0 +1,54 @@
+// vim: tw=80
+//! Unsafe methods can be mocked, too.  Their expectations are safe.
+#![deny(warnings)]
+
+use mockall::*;
+
+#[automock]
+trait Foo {
+    /// # Safety
+    ///
+    /// `x` must be valid for reads
+    unsafe fn foo(&self, x: *const u32) -> u32;
+    /// # Safety
+    ///
+    /// None really
+    unsafe fn bar(x: u32) -> u32;
+}
+
+pub struct Baz {}
+
+#[automock]
+impl Baz {
+    /// # Safety
+    ///
+    /// None really
+    pub unsafe fn baz(&self) -> u32 {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn method() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .returning(|x| unsafe { *x } + 1);
+    let v = 5u32;
+    assert_eq!(6, unsafe { mock.foo(&v) });
+}
+
+#[test]
+fn static_method() {
+    let ctx = MockFoo::bar_context();
+    ctx.expect()
+        .returning(|x| x * 2);
+    assert_eq!(8, unsafe { MockFoo::bar(4) });
+}
+
+#[test]
+fn struct_method() {
+    let mut mock = MockBaz::new();
+    mock.expect_baz()
+        .return_const(42u32);
+    assert_eq!(42, unsafe { mock.baz() });
+}
@@ -1,5 +1,6 @@
 // vim: ts=80
 #![deny(warnings)]
+#![allow(dead_code)]
 
 use mockall::*;
 
@@ -0,0 +1,33 @@
+// vim: tw=80
+//! A trait whose default method implementations can be kept by the mock
+#![deny(warnings)]
+
+use mockall::*;
+
+#[automock]
+trait Foo {
+    fn foo(&self, x: u32) -> u32;
+    #[use_default]
+    fn bar(&self, x: u32) -> u32 {
+        self.foo(x) + 1
+    }
+    fn baz(&self) -> u32 {
+        0
+    }
+}
+
+#[test]
+fn use_default() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .returning(|x| 2 * x);
+    assert_eq!(9, mock.bar(4));
+}
+
+#[test]
+fn overridden_default() {
+    let mut mock = MockFoo::new();
+    mock.expect_baz()
+        .return_const(5u32);
+    assert_eq!(5, mock.baz());
+}
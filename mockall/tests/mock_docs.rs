@@ -1,13 +1,13 @@
 // vim: tw=80
+//! mock! should allow doc comments in all reasonable positions.  This test
+//! ensures that the code will compile.  mockall_derive has a unit test to
+//! ensure that the doc comments are correctly placed.
 #![deny(missing_docs)]
 #![deny(warnings)]
+#![allow(dead_code)]
 
 use mockall::*;
 
-// mock! should allow doc comments in all reasonable positions.  This test
-// ensures that the code will compile.  mockall_derive has a unit test to ensure
-// that the doc comments are correctly placed.
-
 trait Tr {
     fn bar(&self);
 }
@@ -1,5 +1,6 @@
 // vim: tw=80
 #![deny(warnings)]
+#![allow(clippy::multiple_bound_locations)]
 
 use mockall::*;
 
@@ -0,0 +1,51 @@
+// vim: tw=80
+//! Methods that return a Result can use return_ok and return_err
+#![deny(warnings)]
+
+use mockall::*;
+use std::io;
+
+#[derive(Debug, PartialEq)]
+pub struct NonClone(u32);
+
+mock! {
+    Foo {
+        fn foo(&self, x: u32) -> Result<u32, String>;
+        fn bar(&self) -> io::Result<NonClone>;
+        fn baz() -> Result<(), NonClone>;
+    }
+}
+
+#[test]
+fn return_ok() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .return_ok(42);
+    assert_eq!(Ok(42), mock.foo(0));
+    assert_eq!(Ok(42), mock.foo(1));
+}
+
+#[test]
+fn return_ok_once() {
+    let mut mock = MockFoo::new();
+    mock.expect_bar()
+        .return_ok_once(NonClone(42));
+    assert_eq!(NonClone(42), mock.bar().unwrap());
+}
+
+#[test]
+fn return_err() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .return_err(String::from("Oops"));
+    assert_eq!(Err(String::from("Oops")), mock.foo(0));
+    assert_eq!(Err(String::from("Oops")), mock.foo(1));
+}
+
+#[test]
+fn return_err_once() {
+    let ctx = MockFoo::baz_context();
+    ctx.expect()
+        .return_err_once(NonClone(42));
+    assert_eq!(Err(NonClone(42)), MockFoo::baz());
+}
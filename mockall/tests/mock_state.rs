@@ -0,0 +1,62 @@
+// vim: tw=80
+//! Several expectations can share scripted state with a `SharedState`
+#![deny(warnings)]
+
+use mockall::*;
+
+#[automock]
+trait Counter {
+    fn incr(&self, x: u32);
+    fn value(&self) -> u32;
+    fn reset(&self);
+}
+
+#[test]
+fn shared() {
+    let state = SharedState::new(0u32);
+    let mut mock = MockCounter::new();
+    let s = state.clone();
+    mock.expect_incr()
+        .returning(move |x| s.update(|v| *v += x));
+    let s = state.clone();
+    mock.expect_value()
+        .returning(move || s.get());
+    let s = state.clone();
+    mock.expect_reset()
+        .returning(move || s.set(0));
+
+    mock.incr(2);
+    mock.incr(3);
+    assert_eq!(5, mock.value());
+    mock.reset();
+    assert_eq!(0, mock.value());
+    assert_eq!(0, state.get());
+}
+
+#[test]
+fn debug() {
+    let state = SharedState::new(vec![1, 2]);
+    assert_eq!("SharedState([1, 2])", format!("{:?}", state));
+}
+
+#[test]
+fn default() {
+    let state = SharedState::<u32>::default();
+    assert_eq!(0, state.get());
+}
+
+/// A closure that panicked while updating the state doesn't make the state
+/// unusable afterwards
+#[test]
+fn poisoned() {
+    let state = SharedState::new(1u32);
+    let s = state.clone();
+    let r = std::panic::catch_unwind(move || s.update(|v| {
+        *v = 2;
+        panic!("boom");
+    }));
+    assert!(r.is_err());
+    assert_eq!(2, state.get());
+    state.set(3);
+    assert_eq!(3, state.update(|v| *v));
+}
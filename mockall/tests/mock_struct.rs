@@ -52,6 +52,38 @@ mod checkpoint {
         panic!("Shouldn't get here!");
     }
 
+    #[test]
+    #[should_panic(expected =
+        "MockFoo::foo: Expectation(var == 1) called 0 time(s) which is fewer than expected 1\nMockFoo::foo: Expectation(var == 2) called 0 time(s) which is fewer than expected 1")]
+    fn not_yet_satisfied_several() {
+        let mut mock = MockFoo::new();
+        mock.expect_foo()
+            .with(predicate::eq(1))
+            .returning(|_| 42)
+            .times(1);
+        mock.expect_foo()
+            .with(predicate::eq(2))
+            .returning(|_| 42)
+            .times(1);
+        mock.checkpoint();
+        panic!("Shouldn't get here!");
+    }
+
+    /// Unsatisfied expectations of different methods are reported together
+    #[test]
+    #[should_panic(expected =
+        "MockFoo::foo: Expectation(<anything>) called 0 time(s) which is fewer than expected 1\nMockFoo::bar: Expectation(<anything>) called 0 time(s) which is fewer than expected 1")]
+    fn not_yet_satisfied_several_methods() {
+        let mut mock = MockFoo::new();
+        mock.expect_foo()
+            .returning(|_| 42)
+            .times(1);
+        mock.expect_bar()
+            .returning(|_| ())
+            .times(1);
+        mock.checkpoint();
+        panic!("Shouldn't get here!");
+    }
 
     #[test]
     fn ok() {
@@ -122,6 +154,59 @@ mod r#match {
         assert_eq!(99, mock0.foo(5));
     }
 
+    /// A higher priority expectation is used before an older one
+    #[test]
+    fn priority() {
+        let mut mock = MockFoo::new();
+        mock.expect_foo()
+            .with(predicate::always())
+            .returning(|_| 42);
+        mock.expect_foo()
+            .with(predicate::eq(5))
+            .priority(1)
+            .returning(|_| 99);
+
+        assert_eq!(99, mock.foo(5));
+        assert_eq!(42, mock.foo(6));
+    }
+
+    /// Once a higher priority expectation is done, lower priority ones match
+    #[test]
+    fn priority_done() {
+        let mut mock = MockFoo::new();
+        mock.expect_foo()
+            .returning(|_| 42);
+        mock.expect_foo()
+            .times(1)
+            .priority(1)
+            .returning(|_| 99);
+
+        assert_eq!(99, mock.foo(5));
+        assert_eq!(42, mock.foo(5));
+    }
+
+    /// If no expectation of the highest priority matches, the next highest
+    /// priority is tried, still in FIFO order
+    #[test]
+    fn priority_fallthrough() {
+        let mut mock = MockFoo::new();
+        mock.expect_foo()
+            .returning(|_| 0);
+        mock.expect_foo()
+            .with(predicate::eq(7))
+            .priority(2)
+            .returning(|_| 2);
+        mock.expect_foo()
+            .priority(1)
+            .returning(|_| 1);
+        mock.expect_foo()
+            .priority(1)
+            .returning(|_| 11);
+
+        assert_eq!(1, mock.foo(5));
+        assert_eq!(2, mock.foo(7));
+    }
+
     #[test]
     #[cfg_attr(feature = "nightly", should_panic(
             expected = "MockFoo::bar(5): No matching expectation found"
@@ -146,6 +231,107 @@ mod r#match {
         mock.bar(5);
     }
 
+    #[test]
+    fn with_combinators_ok() {
+        let mut mock = MockFoo::new();
+        mock.expect_bar()
+            .with(predicate::gt(0).and(predicate::lt(10)))
+            .return_const(());
+        mock.expect_bar()
+            .with(predicate::eq(42).or(predicate::eq(43)).not())
+            .return_const(());
+        mock.bar(5);
+        mock.bar(50);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "nightly", should_panic(
+            expected = "MockFoo::bar(42): No matching expectation found"
+    ))]
+    #[cfg_attr(not(feature = "nightly"), should_panic(
+            expected = "MockFoo::bar(?): No matching expectation found"
+    ))]
+    fn with_combinators_no_matches() {
+        let mut mock = MockFoo::new();
+        mock.expect_bar()
+            .with(predicate::gt(0).and(predicate::lt(10)))
+            .return_const(());
+        mock.expect_bar()
+            .with(predicate::eq(42).or(predicate::eq(43)).not())
+            .return_const(());
+        mock.bar(42);
+    }
+
+    #[test]
+    fn with_in_range_ok() {
+        let mut mock = MockFoo::new();
+        mock.expect_bar()
+            .with(predicate::in_range(1..=5))
+            .times(2)
+            .return_const(());
+        mock.bar(1);
+        mock.bar(5);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "nightly", should_panic(
+            expected = "MockFoo::bar(5): No matching expectation found"
+    ))]
+    #[cfg_attr(not(feature = "nightly"), should_panic(
+            expected = "MockFoo::bar(?): No matching expectation found"
+    ))]
+    fn with_in_range_no_matches() {
+        let mut mock = MockFoo::new();
+        mock.expect_bar()
+            .with(predicate::in_range(1..5))
+            .return_const(());
+        mock.bar(5);
+    }
+
+    #[test]
+    fn with_captor() {
+        let captor = predicate::Captor::new();
+        let mut mock = MockFoo::new();
+        mock.expect_bar()
+            .with(captor.clone())
+            .return_const(());
+        mock.bar(1);
+        mock.bar(2);
+        assert_eq!(captor.values(), [1, 2]);
+    }
+
+    /// A Captor only records the calls that its own expectation handles
+    #[test]
+    fn with_captor_exhausted() {
+        let captor = predicate::Captor::new();
+        let mut mock = MockFoo::new();
+        mock.expect_bar()
+            .with(captor.clone())
+            .times(1)
+            .return_const(());
+        mock.expect_bar()
+            .return_const(());
+        mock.bar(1);
+        mock.bar(2);
+        mock.bar(3);
+        assert_eq!(captor.values(), [1]);
+    }
+
+    /// Nor the calls that fail its expectation's other predicates
+    #[test]
+    fn with_captor_fallthrough() {
+        let captor = predicate::Captor::new();
+        let mut mock = MockFoo::new();
+        mock.expect_foo()
+            .with(captor.clone().and(predicate::lt(5)))
+            .returning(|_| 0);
+        mock.expect_foo()
+            .returning(|_| 1);
+        assert_eq!(0, mock.foo(2));
+        assert_eq!(1, mock.foo(7));
+        assert_eq!(captor.values(), [2]);
+    }
+
     #[test]
     fn withf_ok() {
         let mut mock = MockFoo::new();
@@ -194,6 +380,27 @@ mod never {
     }
 }
 
+#[test]
+fn notify() {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut mock = MockFoo::new();
+    mock.expect_bar()
+        .notify(tx)
+        .return_const(());
+    let handle = std::thread::spawn(move || mock.bar(0));
+    rx.recv_timeout(std::time::Duration::from_secs(10)).unwrap();
+    handle.join().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Out of cheese")]
+fn panicking() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .panicking("Out of cheese");
+    mock.foo(0);
+}
+
 #[test]
 fn return_const() {
     let mut mock = MockFoo::new();
@@ -213,6 +420,15 @@ fn return_default() {
     assert_eq!(u32::default(), r);
 }
 
+/// return_default works without the nightly feature
+#[test]
+fn return_default_explicit() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .return_default();
+    assert_eq!(u32::default(), mock.foo(5));
+}
+
 #[test]
 fn returning() {
     let mut mock = MockFoo::new();
@@ -221,6 +437,42 @@ fn returning() {
     assert_eq!(6, mock.foo(5));
 }
 
+#[test]
+fn returning_indexed() {
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .returning_indexed(|i, x| x + i as u32 * 10);
+    assert_eq!(5, mock.foo(5));
+    assert_eq!(15, mock.foo(5));
+    assert_eq!(26, mock.foo(6));
+}
+
+mod return_values {
+    use super::*;
+
+    #[test]
+    fn ok() {
+        let mut mock = MockFoo::new();
+        mock.expect_foo()
+            .return_values(vec![1, 2, 3]);
+        assert_eq!(1, mock.foo(0));
+        assert_eq!(2, mock.foo(0));
+        assert_eq!(3, mock.foo(0));
+    }
+
+    #[test]
+    #[should_panic(expected =
+        "MockFoo::foo: Expectation(<anything>) ran out of values to return")]
+    fn exhausted() {
+        let mut mock = MockFoo::new();
+        mock.expect_foo()
+            .return_values(vec![1, 2]);
+        mock.foo(0);
+        mock.foo(0);
+        mock.foo(0);
+    }
+}
+
 mod sequence {
     use super::*;
 
@@ -272,6 +524,49 @@ mod sequence {
         mock.bar(0);
     }
 
+    /// A single Sequence may span multiple mock objects
+    #[test]
+    fn multiple_mocks_ok() {
+        let mut seq = Sequence::new();
+        let mut mock0 = MockFoo::new();
+        let mut mock1 = MockFoo::new();
+        mock0.expect_baz()
+            .times(1)
+            .returning(|| ())
+            .in_sequence(&mut seq);
+        mock1.expect_baz()
+            .times(1)
+            .returning(|| ())
+            .in_sequence(&mut seq);
+        mock0.expect_bar()
+            .times(1)
+            .returning(|_| ())
+            .in_sequence(&mut seq);
+
+        mock0.baz();
+        mock1.baz();
+        mock0.bar(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "MockFoo::baz(): Method sequence violation")]
+    fn multiple_mocks_fail() {
+        let mut seq = Sequence::new();
+        let mut mock0 = MockFoo::new();
+        let mut mock1 = MockFoo::new();
+        mock0.expect_baz()
+            .times(1)
+            .returning(|| ())
+            .in_sequence(&mut seq);
+        mock1.expect_baz()
+            .times(1)
+            .returning(|| ())
+            .in_sequence(&mut seq);
+
+        mock1.baz();
+        mock0.baz();
+    }
+
     /// When adding multiple calls of a single method, with the same arguments,
     /// to a sequence, expectations should not be called after they are done if
     /// there are more expectations to follow.
@@ -324,6 +619,43 @@ mod times {
         mock.bar(5);
     }
 
+    /// When several expectations are unsatisfied, all of them should be
+    /// reported.
+    #[test]
+    #[should_panic(expected =
+        "MockFoo::bar: Expectation(var == 5) called 0 time(s) which is fewer than expected 1\nMockFoo::bar: Expectation(var == 6) called 1 time(s) which is fewer than expected 2")]
+    fn too_few_several() {
+        let mut mock = MockFoo::new();
+        mock.expect_bar()
+            .with(predicate::eq(5))
+            .returning(|_| ())
+            .times(1);
+        mock.expect_bar()
+            .with(predicate::eq(6))
+            .returning(|_| ())
+            .times(2);
+        mock.expect_bar()
+            .with(predicate::eq(7))
+            .returning(|_| ())
+            .times(1);
+        mock.bar(6);
+        mock.bar(7);
+    }
+
+    /// Unsatisfied expectations of different methods are reported together
+    #[test]
+    #[should_panic(expected =
+        "MockFoo::foo: Expectation(<anything>) called 0 time(s) which is fewer than expected 1\nMockFoo::bar: Expectation(<anything>) called 0 time(s) which is fewer than expected 1")]
+    fn too_few_several_methods() {
+        let mut mock = MockFoo::new();
+        mock.expect_foo()
+            .returning(|_| 42)
+            .times(1);
+        mock.expect_bar()
+            .returning(|_| ())
+            .times(1);
+    }
+
     #[test]
     #[should_panic(expected =
         "MockFoo::baz: Expectation(<anything>) called 3 times which is more than the expected 2")]
@@ -459,3 +791,30 @@ fn times_full() {
     mock.baz();
     mock.baz();
 }
+
+#[test]
+fn with_delay() {
+    let delay = std::time::Duration::from_millis(10);
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .with_delay(delay)
+        .return_const(42u32);
+    let start = std::time::Instant::now();
+    assert_eq!(42, mock.foo(0));
+    assert!(start.elapsed() >= delay);
+}
+
+/// The delay applies even if the expectation panics
+#[test]
+fn with_delay_panicking() {
+    let delay = std::time::Duration::from_millis(100);
+    let mut mock = MockFoo::new();
+    mock.expect_foo()
+        .with_delay(delay)
+        .panicking("Out of cheese");
+    let start = std::time::Instant::now();
+    let r = std::panic::catch_unwind(
+        std::panic::AssertUnwindSafe(|| mock.foo(0)));
+    assert!(r.is_err());
+    assert!(start.elapsed() >= delay);
+}
@@ -83,6 +83,38 @@ fn return_default() {
     assert_eq!(u64::default(), r);
 }
 
+#[test]
+fn return_default_explicit() {
+    let _m = BAR_MTX.lock().unwrap();
+
+    let ctx = MockFoo::bar_context();
+    ctx.expect()
+        .return_default();
+    assert_eq!(u64::default(), MockFoo::bar(5));
+}
+
+#[test]
+fn return_values() {
+    let _m = BAR_MTX.lock().unwrap();
+
+    let ctx = MockFoo::bar_context();
+    ctx.expect()
+        .return_values(vec![1, 2]);
+    assert_eq!(1, MockFoo::bar(0));
+    assert_eq!(2, MockFoo::bar(0));
+}
+
+#[test]
+fn returning_indexed() {
+    let _m = BAR_MTX.lock().unwrap();
+
+    let ctx = MockFoo::bar_context();
+    ctx.expect()
+        .returning_indexed(|i, x| u64::from(x) + i as u64);
+    assert_eq!(5, MockFoo::bar(5));
+    assert_eq!(6, MockFoo::bar(5));
+}
+
 #[test]
 fn returning() {
     let _m = BAR_MTX.lock().unwrap();
@@ -107,3 +139,27 @@ fn two_matches() {
     assert_eq!(101, MockFoo::bar(69));
     assert_eq!(99, MockFoo::bar(42));
 }
+
+// A delayed call shouldn't stop other threads from calling the same method
+#[test]
+fn with_delay() {
+    let _m = BAR_MTX.lock().unwrap();
+
+    let delay = std::time::Duration::from_secs(1);
+    let (tx, rx) = std::sync::mpsc::channel();
+    let ctx = MockFoo::bar_context();
+    ctx.expect()
+        .with(predicate::eq(1))
+        .notify(tx)
+        .with_delay(delay)
+        .return_const(1u64);
+    ctx.expect()
+        .with(predicate::eq(2))
+        .return_const(2u64);
+    let start = std::time::Instant::now();
+    let handle = std::thread::spawn(|| MockFoo::bar(1));
+    rx.recv_timeout(delay).unwrap();
+    assert_eq!(2, MockFoo::bar(2));
+    assert!(start.elapsed() < delay);
+    assert_eq!(1, handle.join().unwrap());
+}
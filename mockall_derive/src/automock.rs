@@ -8,6 +8,7 @@ use syn::parse::{Parse, ParseStream};
 #[allow(clippy::large_enum_variant)]
 enum Attr {
     Mod(ItemMod),
+    Struct(ItemStruct),
     Type(TraitItemType),
 }
 
@@ -16,6 +17,8 @@ impl Parse for Attr {
         let lookahead = input.lookahead1();
         if lookahead.peek(Token![mod]) {
             input.parse().map(Attr::Mod)
+        } else if lookahead.peek(Token![struct]) {
+            input.parse().map(Attr::Struct)
         } else if lookahead.peek(Token![type]) {
             input.parse().map(Attr::Type)
         } else {
@@ -28,7 +31,9 @@ impl Parse for Attr {
 #[derive(Debug, Default)]
 pub(crate) struct Attrs {
     pub attrs: HashMap<Ident, Type>,
-    pub modname: Option<Ident>
+    pub modname: Option<Ident>,
+    /// Name of the generated mock struct, if not the default
+    pub structname: Option<Ident>
 }
 
 impl Attrs {
@@ -251,6 +256,7 @@ impl Parse for Attrs {
     fn parse(input: ParseStream) -> parse::Result<Self> {
         let mut attrs = HashMap::new();
         let mut modname = None;
+        let mut structname = None;
         while !input.is_empty() {
             let attr: Attr = input.parse()?;
             match attr {
@@ -261,6 +267,17 @@ impl Parse for Attrs {
                     }
                     modname = Some(item_mod.ident.clone());
                 },
+                Attr::Struct(item_struct) => {
+                    if !matches!(item_struct.fields, Fields::Unit) {
+                        compile_error(item_struct.span(),
+                            "struct name attributes must have the form \"struct MyName;\"");
+                    }
+                    if !item_struct.generics.params.is_empty() {
+                        compile_error(item_struct.generics.span(),
+                            "struct name attributes may not have generic parameters");
+                    }
+                    structname = Some(item_struct.ident.clone());
+                },
                 Attr::Type(trait_item_type) => {
                     let ident = trait_item_type.ident.clone();
                     if let Some((_, ty)) = trait_item_type.default {
@@ -272,7 +289,7 @@ impl Parse for Attrs {
                 }
             }
         }
-        Ok(Attrs{attrs, modname})
+        Ok(Attrs{attrs, modname, structname})
     }
 }
 
@@ -125,6 +125,49 @@ fn deanonymize(literal_type: &mut Type) {
     }
 }
 
+/// Remove from `gen` every type parameter for which `should_remove` returns
+/// true, along with any simple where clause predicates that bound it.
+fn remove_type_params<F>(gen: &Generics, should_remove: F) -> Generics
+    where F: Fn(&Ident) -> bool
+{
+    let params = gen.params.iter()
+        .filter(|g| {
+            if let GenericParam::Type(tp) = g {
+                !should_remove(&tp.ident)
+            } else {
+                true
+            }
+        }).cloned()
+        .collect::<Punctuated<_, _>>();
+    let mut wc2 = gen.where_clause.clone();
+    if let Some(wc) = &mut wc2 {
+        wc.predicates = wc.predicates.iter()
+            .filter(|wp| {
+                if let WherePredicate::Type(pt) = wp {
+                    let bounded_ty = &pt.bounded_ty;
+                    if let Ok(ident) = parse2::<Ident>(quote!(#bounded_ty)) {
+                        !should_remove(&ident)
+                    } else {
+                        // We can't yet handle where clauses this complicated
+                        true
+                    }
+                } else {
+                    true
+                }
+            }).cloned()
+            .collect::<Punctuated<_, _>>();
+        if wc.predicates.is_empty() {
+            wc2 = None;
+        }
+    }
+    Generics {
+        lt_token: if params.is_empty() { None } else { gen.lt_token },
+        gt_token: if params.is_empty() { None } else { gen.gt_token },
+        params,
+        where_clause: wc2
+    }
+}
+
 // If there are any closures in the argument list, turn them into boxed
 // functions
 fn declosurefy(gen: &Generics, args: &Punctuated<FnArg, Token![,]>) ->
@@ -168,46 +211,10 @@ fn declosurefy(gen: &Generics, args: &Punctuated<FnArg, Token![,]>) ->
     }
 
     // Then remove those types from both the Generics' params and where clause
-    let should_remove = |ident: &Ident| {
+    let outg = remove_type_params(gen, |ident: &Ident| {
             let ty: Type = parse2(quote!(#ident)).unwrap();
             hm.contains_key(&ty)
-    };
-    let params = gen.params.iter()
-        .filter(|g| {
-            if let GenericParam::Type(tp) = g {
-                !should_remove(&tp.ident)
-            } else {
-                true
-            }
-        }).cloned()
-        .collect::<Punctuated<_, _>>();
-    let mut wc2 = gen.where_clause.clone();
-    if let Some(wc) = &mut wc2 {
-        wc.predicates = wc.predicates.iter()
-            .filter(|wp| {
-                if let WherePredicate::Type(pt) = wp {
-                    let bounded_ty = &pt.bounded_ty;
-                    if let Ok(ident) = parse2::<Ident>(quote!(#bounded_ty)) {
-                        !should_remove(&ident)
-                    } else {
-                        // We can't yet handle where clauses this complicated
-                        true
-                    }
-                } else {
-                    true
-                }
-            }).cloned()
-            .collect::<Punctuated<_, _>>();
-        if wc.predicates.is_empty() {
-            wc2 = None;
-        }
-    }
-    let outg = Generics {
-        lt_token: if params.is_empty() { None } else { gen.lt_token },
-        gt_token: if params.is_empty() { None } else { gen.gt_token },
-        params,
-        where_clause: wc2
-    };
+    });
 
     // Next substitute Box<Fn> into the arguments
     let outargs = args.iter().map(|arg| {
@@ -256,6 +263,177 @@ fn declosurefy(gen: &Generics, args: &Punctuated<FnArg, Token![,]>) ->
     (outg, outargs, callargs)
 }
 
+/// For a method marked `#[concretize]`, replace every generic argument with a
+/// trait object.
+///
+/// Arguments of type `T`, `&T`, `&mut T`, or `impl Trait` become
+/// `&(dyn Bounds + '__mockall_x)` (or `&mut`), where `Bounds` are all of the
+/// trait bounds of `T` (or of the `impl Trait`) and `x` is the argument's
+/// name.  Like with `add_lifetime_parameters`, the new lifetimes let the
+/// expectation accept non-`'static` arguments.  The generic parameters
+/// themselves are removed, so the expectation is no longer generic.  Using them
+/// anywhere else, including the return type, is an error.
+fn concretize_args(
+    gen: &Generics,
+    args: &Punctuated<FnArg, Token![,]>,
+    rt: &ReturnType)
+    -> (Generics, Vec<FnArg>, Vec<TokenStream>)
+{
+    let mut hm: HashMap<Ident, Punctuated<TypeParamBound, Token![+]>> =
+        HashMap::default();
+
+    let mut save_bounds = |ident: &Ident, bounds: &Punctuated<TypeParamBound,
+        Token![+]>|
+    {
+        let entry = hm.entry(ident.clone()).or_default();
+        for tpb in bounds.iter() {
+            if let TypeParamBound::Trait(tb) = tpb {
+                if tb.modifier != TraitBoundModifier::None {
+                    // "?Sized" can't be part of a trait object
+                    continue;
+                }
+            }
+            entry.push(tpb.clone());
+        }
+    };
+
+    // First, collect the bounds of every generic type
+    for g in gen.params.iter() {
+        if let GenericParam::Type(tp) = g {
+            save_bounds(&tp.ident, &tp.bounds);
+        }
+    }
+    if let Some(wc) = &gen.where_clause {
+        for pred in wc.predicates.iter() {
+            if let WherePredicate::Type(pt) = pred {
+                let bounded_ty = &pt.bounded_ty;
+                if let Ok(ident) = parse2::<Ident>(quote!(#bounded_ty)) {
+                    save_bounds(&ident, &pt.bounds);
+                } else {
+                    // We can't yet handle where clauses this complicated
+                }
+            }
+        }
+    }
+
+    // A trait object needs at least one trait
+    hm.retain(|_, bounds| bounds.iter()
+        .any(|tpb| matches!(tpb, TypeParamBound::Trait(_))));
+
+    // Then remove those types from the Generics
+    let mut outg = remove_type_params(gen, |ident| hm.contains_key(ident));
+
+    // Is one of the removed types used anywhere in ty?
+    let uses_removed = |ty: &Type| hm.keys()
+        .any(|ident| mentions_ident(ty.to_token_stream(), ident));
+
+    // The trait object type for a set of bounds
+    let dyn_ty = |bounds: &Punctuated<TypeParamBound, Token![+]>,
+                  lt: &Lifetime|
+    {
+        let traits = bounds.iter()
+            .filter(|tpb| matches!(tpb, TypeParamBound::Trait(_)));
+        quote!((dyn #(#traits +)* #lt))
+    };
+
+    // Returns the trait object type to substitute for ty, and whether the
+    // original argument must be borrowed to create it.
+    let dynify = |ty: &Type, dlt: &Lifetime| -> Option<(Type, bool)> {
+        match ty {
+            Type::ImplTrait(tit) => {
+                let dt = dyn_ty(&tit.bounds, dlt);
+                Some((parse2(quote!(&#dt)).unwrap(), true))
+            },
+            Type::Path(tp) => {
+                tp.path.get_ident()
+                    .and_then(|ident| hm.get(ident))
+                    .map(|bounds| {
+                        let dt = dyn_ty(bounds, dlt);
+                        (parse2(quote!(&#dt)).unwrap(), true)
+                    })
+            },
+            Type::Reference(tr) => {
+                if let Type::Path(tp) = tr.elem.as_ref() {
+                    tp.path.get_ident()
+                        .and_then(|ident| hm.get(ident))
+                        .map(|bounds| {
+                            let lt = &tr.lifetime;
+                            let m = &tr.mutability;
+                            let dt = dyn_ty(bounds, dlt);
+                            (parse2(quote!(&#lt #m #dt)).unwrap(), false)
+                        })
+                } else {
+                    None
+                }
+            },
+            _ => None
+        }
+    };
+
+    let mut outargs = Vec::new();
+    let mut callargs = Vec::new();
+    let mut lifetimes = Vec::new();
+    for (i, arg) in args.iter().enumerate() {
+        if let FnArg::Typed(pt) = arg {
+            let mut immutable_pt = pt.clone();
+            demutify_arg(&mut immutable_pt);
+            let pat = &immutable_pt.pat;
+            let dlt = if let Pat::Ident(pi) = pat.as_ref() {
+                Lifetime::new(&format!("'__mockall_{}", pi.ident),
+                              Span::call_site())
+            } else {
+                Lifetime::new(&format!("'__mockall_arg{}", i),
+                              Span::call_site())
+            };
+            let newty = dynify(&pt.ty, &dlt);
+            if newty.is_some() {
+                lifetimes.push(GenericParam::Lifetime(LifetimeDef::new(dlt)));
+            } else if uses_removed(&pt.ty) {
+                compile_error(pt.ty.span(),
+                    "#[concretize] only supports generic arguments of type T, &T, or &mut T");
+            }
+            if !pat_is_self(pat) {
+                callargs.push(match newty {
+                    Some((_, true)) => quote!(&#pat),
+                    _ => quote!(#pat)
+                });
+            }
+            outargs.push(FnArg::Typed(PatType {
+                attrs: Vec::default(),
+                pat: immutable_pt.pat.clone(),
+                colon_token: pt.colon_token,
+                ty: Box::new(newty.map(|(ty, _)| ty)
+                             .unwrap_or_else(|| (*pt.ty).clone()))
+            }));
+        } else {
+            outargs.push(arg.clone());
+        }
+    }
+    if let ReturnType::Type(_, ty) = rt {
+        if uses_removed(ty) {
+            compile_error(ty.span(),
+                "#[concretize] does not support methods that return a generic type");
+        }
+    }
+    if !lifetimes.is_empty() {
+        // Lifetimes must precede any remaining type parameters
+        lifetimes.extend(outg.params);
+        outg.params = lifetimes.into_iter().collect();
+        outg.lt_token.get_or_insert(Token![<](Span::call_site()));
+        outg.gt_token.get_or_insert(Token![>](Span::call_site()));
+    }
+    (outg, outargs, callargs)
+}
+
+/// Does `ts` mention `ident` anywhere, including inside of any groups?
+fn mentions_ident(ts: TokenStream, ident: &Ident) -> bool {
+    ts.into_iter().any(|tt| match tt {
+        proc_macro2::TokenTree::Ident(i) => i == *ident,
+        proc_macro2::TokenTree::Group(g) => mentions_ident(g.stream(), ident),
+        _ => false
+    })
+}
+
 /// Replace any "impl trait" types with "Box<dyn trait>" or equivalent.
 fn deimplify(rt: &mut ReturnType) {
     if let ReturnType::Type(_, ty) = rt {
@@ -572,30 +750,84 @@ impl<'a> AttrFormatter<'a> {
     #[allow(clippy::if_same_then_else)]
     fn format(&mut self) -> Vec<Attribute> {
         self.attrs.iter()
-            .cloned()
             .filter(|attr| {
                 let i = attr.path.get_ident();
-                if i.is_none() {
-                    false
-                } else if *i.as_ref().unwrap() == "derive" {
-                    // We can't usefully derive any traits.  Ignore them
-                    false
-                } else if *i.as_ref().unwrap() == "doc" {
-                    self.doc
-                } else if *i.as_ref().unwrap() == "async_trait" {
-                    self.async_trait
-                } else if *i.as_ref().unwrap() == "instrument" {
-                    // We can't usefully instrument the mock method, so just
-                    // ignore this attribute.
-                    // https://docs.rs/tracing/0.1.23/tracing/attr.instrument.html
-                    false
+                if let Some(i) = i {
+                    if *i == "derive" {
+                        // We can't usefully derive any traits.  Ignore them
+                        false
+                    } else if *i == "doc" {
+                        self.doc
+                    } else if *i == "async_trait" {
+                        self.async_trait
+                    } else if is_mockall_attr(attr, "concretize") {
+                        // Mockall handles this attribute itself, even within
+                        // cfg_attr
+                        false
+                    } else if *i == "instrument" {
+                        // We can't usefully instrument the mock method, so
+                        // just ignore this attribute.
+                        // https://docs.rs/tracing/0.1.23/tracing/attr.instrument.html
+                        false
+                    } else {
+                        true
+                    }
                 } else {
-                    true
+                    false
                 }
-            }).collect()
+            }).cloned()
+            .collect()
+    }
+}
+
+/// Is `path` Mockall's attribute `name`, spelled either `name` or
+/// `mockall::name`?
+fn is_mockall_attr_path(path: &Path, name: &str) -> bool {
+    let segs = path.segments.iter()
+        .map(|seg| seg.ident.to_string())
+        .collect::<Vec<_>>();
+    match &segs[..] {
+        [n] => path.leading_colon.is_none() && n == name,
+        [m, n] => m == "mockall" && n == name,
+        _ => false
     }
 }
 
+/// Is this meta Mockall's attribute `name`, possibly nested within
+/// `cfg_attr`?
+fn is_mockall_attr_meta(meta: &Meta, name: &str) -> bool {
+    match meta {
+        Meta::List(ml) if ml.path.is_ident("cfg_attr") => {
+            // The first item is the predicate.  We can't evaluate it, so
+            // assume that it's true, as it will be for the common
+            // `#[cfg_attr(test, automock)]` pattern.
+            ml.nested.iter()
+                .skip(1)
+                .any(|nm| matches!(nm,
+                    NestedMeta::Meta(m) if is_mockall_attr_meta(m, name)))
+        },
+        _ => is_mockall_attr_path(meta.path(), name)
+    }
+}
+
+/// Is this attribute Mockall's attribute `name`, like `#[skip]`, or a
+/// `#[cfg_attr(..., skip)]` that may expand to it?  Other crates' attributes
+/// with the same name, like `#[rustfmt::skip]`, don't count.
+fn is_mockall_attr(attr: &Attribute, name: &str) -> bool {
+    if attr.path.is_ident("cfg_attr") {
+        attr.parse_meta()
+            .map(|meta| is_mockall_attr_meta(&meta, name))
+            .unwrap_or(false)
+    } else {
+        is_mockall_attr_path(&attr.path, name)
+    }
+}
+
+/// Do these attributes include Mockall's attribute `name`?
+fn has_mockall_attr(attrs: &[Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| is_mockall_attr(attr, name))
+}
+
 /// Determine if this Pat is any kind of `self` binding
 fn pat_is_self(pat: &Pat) -> bool {
     if let Pat::Ident(pi) = pat {
@@ -631,7 +863,7 @@ fn supersuperfy_path(path: &mut Path, levels: usize) -> usize {
                     *input = supersuperfy(input, levels);
                 }
                 if let ReturnType::Type(_, ref mut ty) = pga.output {
-                    *ty = Box::new(supersuperfy(ty, levels));
+                    **ty = supersuperfy(ty, levels);
                 }
             },
         }
@@ -1074,6 +1306,35 @@ pub fn automock(attrs: proc_macro::TokenStream, input: proc_macro::TokenStream)
     do_automock(attrs, input).into()
 }
 
+/// `#[automock]` keeps the default implementation of methods with this
+/// attribute.  By itself, it does nothing.
+#[proc_macro_attribute]
+pub fn use_default(_attrs: proc_macro::TokenStream,
+                   input: proc_macro::TokenStream)
+    -> proc_macro::TokenStream
+{
+    input
+}
+
+/// `#[automock]` mocks methods with this attribute using trait objects.  By
+/// itself, it does nothing.
+#[proc_macro_attribute]
+pub fn concretize(_attrs: proc_macro::TokenStream,
+                  input: proc_macro::TokenStream)
+    -> proc_macro::TokenStream
+{
+    input
+}
+
+/// `#[automock]` stubs out methods with this attribute.  By itself, it does
+/// nothing.
+#[proc_macro_attribute]
+pub fn skip(_attrs: proc_macro::TokenStream, input: proc_macro::TokenStream)
+    -> proc_macro::TokenStream
+{
+    input
+}
+
 fn do_automock_once(attrs: TokenStream, input: TokenStream) -> TokenStream {
     let mut output = input.clone();
     let attrs: Attrs = match parse2(attrs) {
@@ -1190,6 +1451,32 @@ mod automock {
     use super::super::*;
     use super::*;
 
+    #[test]
+    #[should_panic(expected = "#[concretize] only supports generic arguments of type T, &T, or &mut T")]
+    fn concretize_other_arg() {
+        let code = r#"
+        trait Foo {
+            #[concretize]
+            fn foo<T: Debug>(&self, x: T, y: Vec<T>);
+        }"#;
+        let ts = proc_macro2::TokenStream::from_str(code).unwrap();
+        let attrs_ts = proc_macro2::TokenStream::from_str("").unwrap();
+        do_automock(attrs_ts, ts).to_string();
+    }
+
+    #[test]
+    #[should_panic(expected = "#[concretize] does not support methods that return a generic type")]
+    fn concretize_return() {
+        let code = r#"
+        trait Foo {
+            #[concretize]
+            fn foo<T: Debug>(&self, x: &T) -> T;
+        }"#;
+        let ts = proc_macro2::TokenStream::from_str(code).unwrap();
+        let attrs_ts = proc_macro2::TokenStream::from_str("").unwrap();
+        do_automock(attrs_ts, ts).to_string();
+    }
+
     #[test]
     fn doc_comments() {
         let code = r#"
@@ -1240,6 +1527,39 @@ mod automock {
         do_automock(attrs_ts, ts).to_string();
     }
 
+    #[test]
+    #[should_panic(expected = "#[skip] is only supported on methods of traits")]
+    fn skip_inherent() {
+        let code = r#"
+        impl Foo {
+            #[skip]
+            fn foo(&self) {}
+        }"#;
+        let ts = proc_macro2::TokenStream::from_str(code).unwrap();
+        let attrs_ts = proc_macro2::TokenStream::from_str("").unwrap();
+        do_automock(attrs_ts, ts).to_string();
+    }
+
+    #[test]
+    #[should_panic(expected = "struct name attributes may not have generic parameters")]
+    fn struct_name_generic() {
+        let code = r#"trait Foo {}"#;
+        let ts = proc_macro2::TokenStream::from_str(code).unwrap();
+        let attrs_ts = proc_macro2::TokenStream::from_str("struct FooDouble<T>;")
+            .unwrap();
+        do_automock(attrs_ts, ts).to_string();
+    }
+
+    #[test]
+    #[should_panic(expected = "struct name attributes are only supported for traits")]
+    fn struct_name_mod() {
+        let code = r#"mod foo { pub fn bar() {} }"#;
+        let ts = proc_macro2::TokenStream::from_str(code).unwrap();
+        let attrs_ts = proc_macro2::TokenStream::from_str("struct FooDouble;")
+            .unwrap();
+        do_automock(attrs_ts, ts).to_string();
+    }
+
     #[test]
     fn trait_visibility() {
         let code = r#"
@@ -94,7 +94,7 @@ fn destrify(ty: &mut Type) {
 /// Return the owned version of the input.
 fn ownify(ty: &Type) -> Type {
     if let Type::Reference(ref tr) = &ty {
-        if tr.lifetime.as_ref().map_or(false, |lt| lt.ident == "static")
+        if tr.lifetime.as_ref().is_some_and(|lt| lt.ident == "static")
         {
             // Just a static expectation
             ty.clone()
@@ -106,6 +106,54 @@ fn ownify(ty: &Type) -> Type {
     }
 }
 
+/// If `ty` is a generic type named `ident`, return its first type argument.
+fn first_type_arg<'a>(ty: &'a Type, ident: &str) -> Option<&'a Type> {
+    if let Type::Path(tp) = ty {
+        let seg = tp.path.segments.last()?;
+        if seg.ident == ident {
+            if let PathArguments::AngleBracketed(abga) = &seg.arguments {
+                if let Some(GenericArgument::Type(t)) = abga.args.first() {
+                    return Some(t);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// If the type is a boxed trait object, like `Box<dyn Foo + Send>`, return the
+/// trait object's trait bounds.  Lifetime bounds are omitted, because they may
+/// name lifetimes that the Expectation doesn't have.
+fn boxed_bounds(ty: &Type) -> Option<Punctuated<TypeParamBound, Token![+]>> {
+    if let Type::TraitObject(tto) = first_type_arg(ty, "Box")? {
+        Some(tto.bounds.iter()
+            .filter(|tpb| matches!(tpb, TypeParamBound::Trait(_)))
+            .cloned()
+            .collect::<Punctuated<_, Token![+]>>())
+    } else {
+        None
+    }
+}
+
+/// If the type is a pinned, boxed `dyn Future`, like the ones that `deimplify`
+/// creates, return the trait object's trait bounds.
+fn future_bounds(ty: &Type) -> Option<Punctuated<TypeParamBound, Token![+]>> {
+    let bounds = boxed_bounds(first_type_arg(ty, "Pin")?)?;
+    let is_future = bounds.iter().any(|tpb| {
+        if let TypeParamBound::Trait(tb) = tpb {
+            tb.path.segments.last()
+                .is_some_and(|seg| seg.ident == "Future")
+        } else {
+            false
+        }
+    });
+    if is_future {
+        Some(bounds)
+    } else {
+        None
+    }
+}
+
 /// Add Send + Sync to a where clause
 fn send_syncify(wc: &mut Option<WhereClause>, bounded_ty: Type) {
     let mut bounds = Punctuated::new();
@@ -168,8 +216,14 @@ impl<'a> Builder<'a> {
         let mut predty = Vec::new();
         let mut refpredty = Vec::new();
 
+        let concretized = has_mockall_attr(self.attrs, "concretize");
         let (mut declosured_generics, declosured_inputs, call_exprs) =
-            declosurefy(&self.sig.generics, &self.sig.inputs);
+            if concretized {
+                concretize_args(&self.sig.generics, &self.sig.inputs,
+                                &self.sig.output)
+            } else {
+                declosurefy(&self.sig.generics, &self.sig.inputs)
+            };
 
         for fa in declosured_inputs.iter() {
             if let FnArg::Typed(pt) = fa {
@@ -213,7 +267,7 @@ impl<'a> Builder<'a> {
                     elems: Punctuated::new()
                 }),
             ReturnType::Type(_, ref ty) => {
-                let mut output_ty = supersuperfy(&**ty, self.levels);
+                let mut output_ty = supersuperfy(ty, self.levels);
                 destrify(&mut output_ty);
                 dedynify(&mut output_ty);
                 output_ty
@@ -221,10 +275,12 @@ impl<'a> Builder<'a> {
         };
         supersuperfy_generics(&mut declosured_generics, self.levels);
         let owned_output = ownify(&output);
+        let boxed_bounds = boxed_bounds(&output);
+        let future_bounds = future_bounds(&output);
         let mut return_ref = false;
         let mut return_refmut = false;
         if let Type::Reference(ref tr) = &output {
-            if tr.lifetime.as_ref().map_or(true, |lt| lt.ident != "static")
+            if tr.lifetime.as_ref().is_none_or(|lt| lt.ident != "static")
             {
                 if tr.mutability.is_none() {
                     return_ref = true;
@@ -260,7 +316,6 @@ impl<'a> Builder<'a> {
         let alifetimes = salifetimes.into_iter()
             .collect::<HashSet<LifetimeDef>>()
             .union(&malifetimes.into_iter().collect::<HashSet<_>>())
-            .into_iter()
             .cloned()
             .collect();
 
@@ -280,6 +335,8 @@ impl<'a> Builder<'a> {
             egenerics,
             cgenerics,
             fn_params,
+            boxed_bounds,
+            future_bounds,
             is_static,
             mod_ident: self.parent.unwrap_or(&Ident::new("FIXME", Span::call_site())).clone(),
             output,
@@ -378,6 +435,11 @@ pub(crate) struct MockFunction {
     cgenerics: Generics,
     /// The mock function's generic types as a list of types
     fn_params: Vec<Ident>,
+    /// If the method returns a boxed trait object, the trait object's bounds
+    boxed_bounds: Option<Punctuated<TypeParamBound, Token![+]>>,
+    /// If the method returns a pinned, boxed `dyn Future`, the trait object's
+    /// bounds
+    future_bounds: Option<Punctuated<TypeParamBound, Token![+]>>,
     /// Is this for a static method or free function?
     is_static: bool,
     /// name of the function's parent module
@@ -459,6 +521,8 @@ impl MockFunction {
                 #(#attrs)*
                 #vis #sig {
                     let no_match_msg = #no_match_msg;
+                    // Sleep, if need be, after releasing the lock
+                    let __mockall_delay = ::mockall::PendingDelays::enter();
                     {
                         let __mockall_guard = #outer_mod_path::EXPECTATIONS
                             .lock().unwrap();
@@ -479,8 +543,9 @@ impl MockFunction {
                 #(#attrs)*
                 #vis #sig {
                     let no_match_msg = #no_match_msg;
+                    let __mockall_delay = ::mockall::PendingDelays::enter();
                     self.#substruct_obj #name.#call#tbf(#(#call_exprs,)*)
-                    .expect(&no_match_msg)
+                        .expect(&no_match_msg)
                 }
 
             )
@@ -499,8 +564,7 @@ impl MockFunction {
                 {
                     let __mockall_timeses = #inner_mod_ident::EXPECTATIONS.lock()
                         .unwrap()
-                        .checkpoint()
-                        .collect::<Vec<_>>();
+                        .checkpoint();
                 }
             )
         } else {
@@ -509,6 +573,16 @@ impl MockFunction {
         }
     }
 
+    /// Return this method's contribution to its parent's list of unsatisfied
+    /// expectations.  Only applicable to non-static methods.
+    pub fn unsatisfied(&self) -> impl ToTokens {
+        let attrs = AttrFormatter::new(&self.attrs)
+            .doc(false)
+            .format();
+        let name = &self.name();
+        quote!(#(#attrs)* __mockall_msgs.extend(self.#name.unsatisfied());)
+    }
+
     /// Return a function that creates a Context object for this function
     ///
     /// # Arguments
@@ -557,8 +631,8 @@ impl MockFunction {
     ///
     /// * `modname`:    Name of the parent struct's private module
     /// * `self_args`:  If supplied, these are the
-    ///                 AngleBracketedGenericArguments of the self type of the
-    ///                 trait impl.  e.g. The `T` in `impl Foo for Bar<T>`.
+    ///   AngleBracketedGenericArguments of the self type of the trait impl.
+    ///   e.g. The `T` in `impl Foo for Bar<T>`.
     // Supplying modname is an unfortunately hack.  Ideally MockFunction
     // wouldn't need to know that.
     pub fn expect(&self, modname: &Ident, self_args: Option<&PathArguments>)
@@ -825,10 +899,28 @@ impl<'a> ToTokens for Common<'a> {
         let boxed_withargs = argnames.iter()
             .map(|aa| quote!(Box::new(#aa), ))
             .collect::<TokenStream>();
+        // Only futures can be delayed without blocking the thread
+        let is_future = self.f.future_bounds.is_some();
+        let async_delay_field = is_future.then(|| quote!(
+            async_delay: Option<::std::time::Duration>,
+        ));
+        let async_delay_default = is_future.then(|| quote!(
+            async_delay: None,
+        ));
+        let async_delay_method = is_future.then(|| quote!(
+            fn with_async_delay(&mut self, __mockall_d: ::std::time::Duration)
+            {
+                self.async_delay = Some(__mockall_d);
+            }
+        ));
         quote!(
             /// Holds the stuff that is independent of the output type
             struct Common #ig #wc {
+                #async_delay_field
+                delay: Option<::std::time::Duration>,
                 matcher: Mutex<Matcher #tg>,
+                notifier: Option<::std::sync::mpsc::Sender<()>>,
+                priority: i32,
                 seq_handle: Option<::mockall::SeqHandle>,
                 times: ::mockall::Times
             }
@@ -837,7 +929,11 @@ impl<'a> ToTokens for Common<'a> {
             {
                 fn default() -> Self {
                     Common {
+                        #async_delay_default
+                        delay: None,
                         matcher: Mutex::new(Matcher::default()),
+                        notifier: None,
+                        priority: 0,
                         seq_handle: None,
                         times: ::mockall::Times::default()
                     }
@@ -853,10 +949,17 @@ impl<'a> ToTokens for Common<'a> {
                             panic!("{}: Expectation({}) {}", #funcname, desc,
                                 m);
                         });
+                    ::mockall::PendingCaptures::commit();
                     self.verify_sequence(desc);
                     if self.times.is_satisfied() {
                         self.satisfy_sequence()
                     }
+                    if let Some(__mockall_tx) = &self.notifier {
+                        // It's fine if the receiver has stopped listening
+                        let _ = __mockall_tx.send(());
+                    }
+                    // The mock method sleeps once it has released its locks
+                    ::mockall::PendingDelays::set(self.delay);
                 }
 
                 fn in_sequence(&mut self, __mockall_seq: &mut ::mockall::Sequence)
@@ -874,6 +977,7 @@ impl<'a> ToTokens for Common<'a> {
 
                 #[allow(clippy::ptr_arg)]
                 fn matches #lg (&self, #( #argnames: &#predty, )*) -> bool {
+                    ::mockall::PendingCaptures::discard();
                     self.matcher.lock().unwrap().matches(#(#argnames, )*)
                 }
 
@@ -882,6 +986,15 @@ impl<'a> ToTokens for Common<'a> {
                     self.times.never();
                 }
 
+                fn notify(&mut self, __mockall_tx: ::std::sync::mpsc::Sender<()>)
+                {
+                    self.notifier = Some(__mockall_tx);
+                }
+
+                fn priority(&mut self, __mockall_n: i32) {
+                    self.priority = __mockall_n;
+                }
+
                 fn satisfy_sequence(&self) {
                     if let Some(__mockall_handle) = &self.seq_handle {
                         __mockall_handle.satisfy()
@@ -896,6 +1009,12 @@ impl<'a> ToTokens for Common<'a> {
                     self.times.times(__mockall_r)
                 }
 
+                #async_delay_method
+
+                fn with_delay(&mut self, __mockall_d: ::std::time::Duration) {
+                    self.delay = Some(__mockall_d);
+                }
+
                 fn with<#with_generics>(&mut self, #with_args)
                 {
                     let mut __mockall_guard = self.matcher.lock().unwrap();
@@ -923,6 +1042,22 @@ impl<'a> ToTokens for Common<'a> {
                         );
                 }
 
+                /// If this expectation hasn't yet been called as many times as
+                /// required, describe the failure.
+                fn unsatisfied(&self) -> Option<::std::string::String> {
+                    if self.times.is_satisfied() {
+                        None
+                    } else {
+                        let desc = std::format!(
+                            "{}", self.matcher.lock().unwrap());
+                        Some(std::format!("{}: Expectation({}) called {} time(s) which is fewer than expected {}",
+                               #funcname,
+                               desc,
+                               self.times.count(),
+                               self.times.minimum()))
+                    }
+                }
+
                 fn verify_sequence(&self, desc: &str) {
                     if let Some(__mockall_handle) = &self.seq_handle {
                         __mockall_handle.verify(desc)
@@ -932,15 +1067,10 @@ impl<'a> ToTokens for Common<'a> {
 
             impl #ig Drop for Common #tg #wc {
                 fn drop(&mut self) {
-                    if !::std::thread::panicking() && !self.times.is_satisfied()
-                    {
-                        let desc = std::format!(
-                            "{}", self.matcher.lock().unwrap());
-                        panic!("{}: Expectation({}) called {} time(s) which is fewer than expected {}",
-                               #funcname,
-                               desc,
-                               self.times.count(),
-                               self.times.minimum());
+                    if !::std::thread::panicking() {
+                        if let Some(__mockall_m) = self.unsatisfied() {
+                            panic!("{}", __mockall_m);
+                        }
                     }
                 }
             }
@@ -998,6 +1128,18 @@ impl<'a> ToTokens for CommonExpectationMethods<'a> {
                 self
             }
 
+            /// Send a message on the given channel every time this expectation
+            /// is called.
+            ///
+            /// This is useful for waiting until code under test, running on
+            /// another thread, has made the expected call.
+            #v fn notify(&mut self, __mockall_tx: ::std::sync::mpsc::Sender<()>)
+                -> &mut Self
+            {
+                self.common.notify(__mockall_tx);
+                self
+            }
+
             /// Create a new, default, [`Expectation`](struct.Expectation.html)
             #v fn new() -> Self {
                 Self::default()
@@ -1009,6 +1151,17 @@ impl<'a> ToTokens for CommonExpectationMethods<'a> {
                 self.times(1)
             }
 
+            /// Set this expectation's priority.
+            ///
+            /// When several expectations match a call, the one with the
+            /// highest priority is used, regardless of the order in which they
+            /// were set.  Expectations with equal priority are tried in FIFO
+            /// order.  The default priority is 0.
+            #v fn priority(&mut self, __mockall_n: i32) -> &mut Self {
+                self.common.priority(__mockall_n);
+                self
+            }
+
             /// Restrict the number of times that that this method may be called.
             ///
             /// The argument may be:
@@ -1038,6 +1191,20 @@ impl<'a> ToTokens for CommonExpectationMethods<'a> {
                 self
             }
 
+            /// Sleep for the given duration every time this expectation is
+            /// called, before returning.
+            ///
+            /// This is useful for testing how the caller handles slow
+            /// collaborators.  Note that the whole thread sleeps, so this
+            /// blocks an async executor's worker thread too.  For methods that
+            /// return a future, `with_async_delay` doesn't.
+            #v fn with_delay(&mut self, __mockall_d: ::std::time::Duration)
+                -> &mut Self
+            {
+                self.common.with_delay(__mockall_d);
+                self
+            }
+
             /// Set a matching function for this Expectation.
             ///
             /// This is equivalent to calling [`with`](#method.with) with a
@@ -1080,11 +1247,11 @@ impl<'a> ToTokens for CommonExpectationsMethods<'a> {
             #v struct Expectations #ig ( Vec<Expectation #tg>) #wc;
 
             impl #ig Expectations #tg #wc {
-                /// Verify that all current expectations are satisfied and clear
-                /// them.
-                #v fn checkpoint(&mut self) -> std::vec::Drain<Expectation #tg>
+                /// Clear all current expectations.  They will be verified when
+                /// the returned object drops.
+                #v fn checkpoint(&mut self) -> Self
                 {
-                    self.0.drain(..)
+                    Expectations(mem::take(&mut self.0))
                 }
 
                 /// Create a new expectation for this method.
@@ -1098,6 +1265,17 @@ impl<'a> ToTokens for CommonExpectationsMethods<'a> {
                 #v fn new() -> Self {
                     Self::default()
                 }
+
+                /// Describe every expectation that hasn't yet been called as
+                /// many times as required.
+                #v fn unsatisfied(&self)
+                    -> ::std::vec::Vec<::std::string::String>
+                {
+                    self.0.iter()
+                        .filter_map(|__mockall_e|
+                            __mockall_e.common.unsatisfied()
+                        ).collect()
+                }
             }
             impl #ig Default for Expectations #tg #wc
             {
@@ -1105,6 +1283,18 @@ impl<'a> ToTokens for CommonExpectationsMethods<'a> {
                     Expectations(Vec::new())
                 }
             }
+            impl #ig Drop for Expectations #tg #wc {
+                /// Verify that every expectation has been satisfied, reporting
+                /// all of the ones that haven't been.
+                fn drop(&mut self) {
+                    if !::std::thread::panicking() {
+                        let __mockall_msgs = self.unsatisfied();
+                        if !__mockall_msgs.is_empty() {
+                            panic!("{}", __mockall_msgs.join("\n"));
+                        }
+                    }
+                }
+            }
         ).to_tokens(tokens);
     }
 }
@@ -1150,7 +1340,54 @@ impl<'a> ToTokens for ExpectationGuardCommonMethods<'a> {
             .map(|(argname, id)| quote!(#argname: #id, ))
             .collect::<TokenStream>();
         let v = &self.f.privmod_vis;
+        let future_methods = self.f.future_bounds.as_ref().map(|bounds| quote!(
+            /// Just like
+            /// [`Expectation::return_future`](struct.Expectation.html#method.return_future)
+            #v fn return_future<MockallFut>(&mut self, __mockall_fut: MockallFut)
+                -> &mut Expectation #tg
+                where MockallFut: #bounds + Send + 'static
+            {
+                #expectations.0[self.i].return_future(__mockall_fut)
+            }
+
+            /// Just like
+            /// [`Expectation::returning_async`](struct.Expectation.html#method.returning_async)
+            #v fn returning_async<MockallF, MockallFut>(&mut self,
+                                                        __mockall_f: MockallF)
+                -> &mut Expectation #tg
+                where MockallF: #hrtb FnMut(#(#argty, )*) -> MockallFut
+                                + Send + 'static,
+                      MockallFut: #bounds + 'static
+            {
+                #expectations.0[self.i].returning_async(__mockall_f)
+            }
+
+            /// Just like
+            /// [`Expectation::with_async_delay`](struct.Expectation.html#method.with_async_delay)
+            #v fn with_async_delay(&mut self,
+                                   __mockall_d: ::std::time::Duration)
+                -> &mut Expectation #tg
+            {
+                #expectations.0[self.i].with_async_delay(__mockall_d)
+            }
+        ));
+        let boxed_methods = self.f.boxed_bounds.as_ref().map(|bounds| quote!(
+            /// Just like
+            /// [`Expectation::returning_boxed`](struct.Expectation.html#method.returning_boxed)
+            #v fn returning_boxed<MockallF, MockallR>(&mut self,
+                                                      __mockall_f: MockallF)
+                -> &mut Expectation #tg
+                where MockallF: #hrtb FnMut(#(#argty, )*) -> MockallR
+                                + Send + 'static,
+                      MockallR: #bounds + 'static
+            {
+                #expectations.0[self.i].returning_boxed(__mockall_f)
+            }
+        ));
         quote!(
+            #boxed_methods
+            #future_methods
+
             /// Just like
             /// [`Expectation::in_sequence`](struct.Expectation.html#method.in_sequence)
             #v fn in_sequence(&mut self,
@@ -1166,12 +1403,38 @@ impl<'a> ToTokens for ExpectationGuardCommonMethods<'a> {
                 #expectations.0[self.i].never()
             }
 
+            /// Just like
+            /// [`Expectation::notify`](struct.Expectation.html#method.notify)
+            #v fn notify(&mut self,
+                __mockall_tx: ::std::sync::mpsc::Sender<()>)
+                -> &mut Expectation #tg
+            {
+                #expectations.0[self.i].notify(__mockall_tx)
+            }
+
             /// Just like
             /// [`Expectation::once`](struct.Expectation.html#method.once)
             #v fn once(&mut self) -> &mut Expectation #tg {
                 #expectations.0[self.i].once()
             }
 
+            /// Just like
+            /// [`Expectation::priority`](struct.Expectation.html#method.priority)
+            #v fn priority(&mut self, __mockall_n: i32)
+                -> &mut Expectation #tg
+            {
+                #expectations.0[self.i].priority(__mockall_n)
+            }
+
+            /// Just like
+            /// [`Expectation::panicking`](struct.Expectation.html#method.panicking)
+            #v fn panicking<MockallM>(&mut self, __mockall_m: MockallM)
+                -> &mut Expectation #tg
+                where MockallM: ::std::fmt::Display + Send + 'static
+            {
+                #expectations.0[self.i].panicking(__mockall_m)
+            }
+
             /// Just like
             /// [`Expectation::return_const`](struct.Expectation.html#method.return_const)
             #v fn return_const<MockallOutput>
@@ -1192,6 +1455,68 @@ impl<'a> ToTokens for ExpectationGuardCommonMethods<'a> {
                 #expectations.0[self.i].return_const_st(__mockall_c)
             }
 
+            /// Just like
+            /// [`Expectation::return_default`](struct.Expectation.html#method.return_default)
+            #v fn return_default(&mut self) -> &mut Expectation #tg
+                where for<'__mockall_rd> #output: Default
+            {
+                #expectations.0[self.i].return_default()
+            }
+
+            /// Just like
+            /// [`Expectation::return_ok`](struct.Expectation.html#method.return_ok)
+            #v fn return_ok<MockallT>(&mut self, __mockall_v: MockallT)
+                -> &mut Expectation #tg
+                where for<'__mockall_rd> #output:
+                          ::mockall::ResultLike<Ok=MockallT>,
+                      MockallT: Clone + Send + 'static
+            {
+                #expectations.0[self.i].return_ok(__mockall_v)
+            }
+
+            /// Just like
+            /// [`Expectation::return_ok_once`](struct.Expectation.html#method.return_ok_once)
+            #v fn return_ok_once<MockallT>(&mut self, __mockall_v: MockallT)
+                -> &mut Expectation #tg
+                where for<'__mockall_rd> #output:
+                          ::mockall::ResultLike<Ok=MockallT>,
+                      MockallT: Send + 'static
+            {
+                #expectations.0[self.i].return_ok_once(__mockall_v)
+            }
+
+            /// Just like
+            /// [`Expectation::return_err`](struct.Expectation.html#method.return_err)
+            #v fn return_err<MockallE>(&mut self, __mockall_e: MockallE)
+                -> &mut Expectation #tg
+                where for<'__mockall_rd> #output:
+                          ::mockall::ResultLike<Err=MockallE>,
+                      MockallE: Clone + Send + 'static
+            {
+                #expectations.0[self.i].return_err(__mockall_e)
+            }
+
+            /// Just like
+            /// [`Expectation::return_err_once`](struct.Expectation.html#method.return_err_once)
+            #v fn return_err_once<MockallE>(&mut self, __mockall_e: MockallE)
+                -> &mut Expectation #tg
+                where for<'__mockall_rd> #output:
+                          ::mockall::ResultLike<Err=MockallE>,
+                      MockallE: Send + 'static
+            {
+                #expectations.0[self.i].return_err_once(__mockall_e)
+            }
+
+            /// Just like
+            /// [`Expectation::return_values`](struct.Expectation.html#method.return_values)
+            #v fn return_values<MockallI>(&mut self, __mockall_i: MockallI)
+                -> &mut Expectation #tg
+                where MockallI: IntoIterator<Item=#output>,
+                      MockallI::IntoIter: Send + 'static
+            {
+                #expectations.0[self.i].return_values(__mockall_i)
+            }
+
             /// Just like
             /// [`Expectation::returning`](struct.Expectation.html#method.returning)
             #v fn returning<MockallF>(&mut self, __mockall_f: MockallF)
@@ -1233,6 +1558,16 @@ impl<'a> ToTokens for ExpectationGuardCommonMethods<'a> {
                 #expectations.0[self.i].returning_st(__mockall_f)
             }
 
+            /// Just like
+            /// [`Expectation::returning_indexed`](struct.Expectation.html#method.returning_indexed)
+            #v fn returning_indexed<MockallF>(&mut self, __mockall_f: MockallF)
+                -> &mut Expectation #tg
+                where MockallF: #hrtb FnMut(usize, #(#argty, )*)
+                                -> #output + Send + 'static
+            {
+                #expectations.0[self.i].returning_indexed(__mockall_f)
+            }
+
             /// Just like
             /// [`Expectation::times`](struct.Expectation.html#method.times)
             #v fn times<MockallR>(&mut self, __mockall_r: MockallR)
@@ -1250,6 +1585,14 @@ impl<'a> ToTokens for ExpectationGuardCommonMethods<'a> {
                 #expectations.0[self.i].with(#(#argnames, )*)
             }
 
+            /// Just like
+            /// [`Expectation::with_delay`](struct.Expectation.html#method.with_delay)
+            #v fn with_delay(&mut self, __mockall_d: ::std::time::Duration)
+                -> &mut Expectation #tg
+            {
+                #expectations.0[self.i].with_delay(__mockall_d)
+            }
+
             /// Just like
             /// [`Expectation::withf`](struct.Expectation.html#method.withf)
             #v fn withf<MockallF>(&mut self, __mockall_f: MockallF)
@@ -1474,8 +1817,7 @@ impl<'a> ToTokens for Context<'a> {
                     let __mockall_timeses = EXPECTATIONS
                         .lock()
                         .unwrap()
-                        .checkpoint()
-                        .collect::<Vec<_>>();
+                        .checkpoint();
                 }
 
                 /// Create a new expectation for this method.
@@ -1772,6 +2114,8 @@ impl<'a> ToTokens for StaticRfunc<'a> {
                 OnceSt(::mockall::Fragile<
                     Box<dyn #hrtb FnOnce(#(#argty, )*) -> #output>>
                 ),
+                // Returns each of a sequence of values, one per call
+                Seq(Box<dyn Iterator<Item=#output> + Send>),
                 // Prevent "unused type parameter" errors Surprisingly,
                 // PhantomData<Fn(generics)> is Send even if generics are not,
                 // unlike PhantomData<generics>
@@ -1813,6 +2157,10 @@ impl<'a> ToTokens for StaticRfunc<'a> {
                                 unreachable!()
                             }
                         },
+                        Rfunc::Seq(__mockall_i) => {
+                            __mockall_i.next()
+                                .ok_or("ran out of values to return")
+                        },
                         Rfunc::_Phantom(_) => unreachable!()
                     }
                 }
@@ -1998,6 +2346,82 @@ impl<'a> ToTokens for StaticExpectation<'a> {
         let lg = lifetimes_to_generics(&self.f.alifetimes);
         let output = &self.f.output;
         let v = &self.f.privmod_vis;
+        let future_methods = self.f.future_bounds.as_ref().map(|bounds| quote!(
+            /// Return the given future from the `Expectation`, boxing and
+            /// pinning it.  Like [`return_once`](#method.return_once), it
+            /// is an error to call the mock method more than once.
+            #[allow(unused_variables)]
+            #v fn return_future<MockallFut>(&mut self, __mockall_fut: MockallFut)
+                -> &mut Self
+                where MockallFut: #bounds + Send + 'static
+            {
+                self.return_once(move |#(#argnames, )*| {
+                    let __mockall_o: #output = Box::pin(__mockall_fut);
+                    __mockall_o
+                })
+            }
+
+            /// Supply a closure that will create the future returned by this
+            /// `Expectation`, for example with an `async` block.  The future
+            /// is boxed and pinned automatically.
+            #v fn returning_async<MockallF, MockallFut>(&mut self,
+                                                        mut __mockall_f: MockallF)
+                -> &mut Self
+                where MockallF: #hrtb FnMut(#(#argty, )*) -> MockallFut
+                                + Send + 'static,
+                      MockallFut: #bounds + 'static
+            {
+                self.returning(move |#(#argnames, )*| {
+                    let __mockall_o: #output =
+                        Box::pin(__mockall_f(#(#argnames, )*));
+                    __mockall_o
+                })
+            }
+
+            /// Make the returned future wait for the given duration, counted
+            /// from the call, before it completes.
+            ///
+            /// Unlike [`with_delay`](#method.with_delay), this doesn't block
+            /// the calling thread, so it's suitable for testing timeouts in
+            /// async code.  It works with any way of setting the return value,
+            /// and with any async runtime.
+            #v fn with_async_delay(&mut self,
+                                   __mockall_d: ::std::time::Duration)
+                -> &mut Self
+            {
+                self.common.with_async_delay(__mockall_d);
+                self
+            }
+        ));
+        let delay_future = self.f.future_bounds.as_ref().map(|_| quote!(
+            let __mockall_o = match self.common.async_delay {
+                Some(__mockall_d) => {
+                    let __mockall_o: #output = Box::pin(
+                        ::mockall::Delayed::new(__mockall_d, __mockall_o));
+                    __mockall_o
+                },
+                None => __mockall_o
+            };
+        ));
+        let boxed_methods = self.f.boxed_bounds.as_ref().map(|bounds| quote!(
+            /// Supply a closure that will create the trait object returned by
+            /// this `Expectation`.  The closure's return value is boxed
+            /// automatically, so a constructor like `MyFake::new` can be used
+            /// directly.
+            #v fn returning_boxed<MockallF, MockallR>(&mut self,
+                                                      mut __mockall_f: MockallF)
+                -> &mut Self
+                where MockallF: #hrtb FnMut(#(#argty, )*) -> MockallR
+                                + Send + 'static,
+                      MockallR: #bounds + 'static
+            {
+                self.returning(move |#(#argnames, )*| {
+                    let __mockall_o: #output =
+                        Box::new(__mockall_f(#(#argnames, )*));
+                    __mockall_o
+                })
+            }
+        ));
 
         quote!(
             /// Expectation type for methods that return a `'static` type.
@@ -2014,13 +2438,16 @@ impl<'a> ToTokens for StaticExpectation<'a> {
                 #v fn call #lg (&self, #(#argnames: #argty, )* ) -> #output
                 {
                     self.common.call(&#desc);
-                    self.rfunc.lock().unwrap().call_mut(#(#argnames, )*)
+                    let __mockall_o = self.rfunc.lock().unwrap()
+                        .call_mut(#(#argnames, )*)
                         .unwrap_or_else(|message| {
                             let desc = std::format!(
                                 "{}", self.common.matcher.lock().unwrap());
                             panic!("{}: Expectation({}) {}", #funcname, desc,
                                    message);
-                        })
+                        });
+                    #delay_future
+                    __mockall_o
                 }
 
                 /// Return a constant value from the `Expectation`
@@ -2065,6 +2492,98 @@ impl<'a> ToTokens for StaticExpectation<'a> {
                     self.returning_st(move |#(#argnames, )*| __mockall_c.clone().into())
                 }
 
+                /// Return the default value of the output type from the
+                /// `Expectation`.
+                ///
+                /// The output type must implement `Default`.  Unlike the
+                /// automatic default return values, this does not require the
+                /// **nightly** feature.
+                // The HRTB postpones checking the bound until the method is
+                // used, so it can be generated for any output type.
+                #[allow(unused_variables)]
+                #v fn return_default(&mut self) -> &mut Self
+                    where for<'__mockall_rd> #output: Default
+                {
+                    self.returning(move |#(#argnames, )*|
+                        <#output as Default>::default())
+                }
+
+                /// Make the mock method panic with the given message when this
+                /// `Expectation` is called.
+                ///
+                /// This is useful for testing how the caller copes with
+                /// panics, for example with `catch_unwind`.
+                #[allow(unused_variables)]
+                #v fn panicking<MockallM>(&mut self, __mockall_m: MockallM)
+                    -> &mut Self
+                    where MockallM: ::std::fmt::Display + Send + 'static
+                {
+                    self.returning(move |#(#argnames, )*|
+                        panic!("{}", __mockall_m))
+                }
+
+                /// Return a clone of the given value, wrapped in `Ok`, from the
+                /// `Expectation`.
+                ///
+                /// This is only usable for methods that return a `Result`.
+                // As in return_default, the HRTB postpones checking the bound
+                // until the method is used.
+                #[allow(unused_variables)]
+                #v fn return_ok<MockallT>(&mut self, __mockall_v: MockallT)
+                    -> &mut Self
+                    where for<'__mockall_rd> #output:
+                              ::mockall::ResultLike<Ok=MockallT>,
+                          MockallT: Clone + Send + 'static
+                {
+                    self.returning(move |#(#argnames, )*|
+                        <#output as ::mockall::ResultLike>::from_ok(
+                            __mockall_v.clone()))
+                }
+
+                /// Like [`return_ok`](#method.return_ok), but for values that
+                /// aren't `Clone`.  It is an error to call the mock method more
+                /// than once.
+                #[allow(unused_variables)]
+                #v fn return_ok_once<MockallT>(&mut self, __mockall_v: MockallT)
+                    -> &mut Self
+                    where for<'__mockall_rd> #output:
+                              ::mockall::ResultLike<Ok=MockallT>,
+                          MockallT: Send + 'static
+                {
+                    self.return_once(move |#(#argnames, )*|
+                        <#output as ::mockall::ResultLike>::from_ok(__mockall_v))
+                }
+
+                /// Return a clone of the given error, wrapped in `Err`, from
+                /// the `Expectation`.
+                ///
+                /// This is only usable for methods that return a `Result`.
+                #[allow(unused_variables)]
+                #v fn return_err<MockallE>(&mut self, __mockall_e: MockallE)
+                    -> &mut Self
+                    where for<'__mockall_rd> #output:
+                              ::mockall::ResultLike<Err=MockallE>,
+                          MockallE: Clone + Send + 'static
+                {
+                    self.returning(move |#(#argnames, )*|
+                        <#output as ::mockall::ResultLike>::from_err(
+                            __mockall_e.clone()))
+                }
+
+                /// Like [`return_err`](#method.return_err), but for errors that
+                /// aren't `Clone`.  It is an error to call the mock method more
+                /// than once.
+                #[allow(unused_variables)]
+                #v fn return_err_once<MockallE>(&mut self, __mockall_e: MockallE)
+                    -> &mut Self
+                    where for<'__mockall_rd> #output:
+                              ::mockall::ResultLike<Err=MockallE>,
+                          MockallE: Send + 'static
+                {
+                    self.return_once(move |#(#argnames, )*|
+                        <#output as ::mockall::ResultLike>::from_err(__mockall_e))
+                }
+
                 /// Supply an `FnOnce` closure that will provide the return
                 /// value for this Expectation.  This is useful for return types
                 /// that aren't `Clone`.  It will be an error to call this
@@ -2103,6 +2622,25 @@ impl<'a> ToTokens for StaticExpectation<'a> {
                     self
                 }
 
+                /// Return each of a sequence of values, one per call.
+                ///
+                /// The mock method will panic if it is called again after the
+                /// sequence is exhausted.  Combine this with
+                /// [`times`](#method.times) to require that every value gets
+                /// used.
+                #v fn return_values<MockallI>(&mut self, __mockall_i: MockallI)
+                    -> &mut Self
+                    where MockallI: IntoIterator<Item=#output>,
+                          MockallI::IntoIter: Send + 'static
+                {
+                    {
+                        let mut __mockall_guard = self.rfunc.lock().unwrap();
+                        *__mockall_guard.deref_mut() =
+                            Rfunc::Seq(Box::new(__mockall_i.into_iter()));
+                    }
+                    self
+                }
+
                 /// Supply a closure that will provide the return value for this
                 /// `Expectation`.  The method's arguments are passed to the
                 /// closure by value.
@@ -2138,6 +2676,26 @@ impl<'a> ToTokens for StaticExpectation<'a> {
                     self
                 }
 
+                /// Like [`returning`](#method.returning), but the closure also
+                /// receives the zero-based index of the current call to this
+                /// `Expectation`, before the method's arguments.
+                #v fn returning_indexed<MockallF>(&mut self,
+                                                  mut __mockall_f: MockallF)
+                    -> &mut Self
+                    where MockallF: #hrtb FnMut(usize, #(#argty, )*)
+                                    -> #output + Send + 'static
+                {
+                    let mut __mockall_idx = 0usize;
+                    self.returning(move |#(#argnames, )*| {
+                        let __mockall_i = __mockall_idx;
+                        __mockall_idx += 1;
+                        __mockall_f(__mockall_i, #(#argnames, )*)
+                    })
+                }
+
+                #boxed_methods
+                #future_methods
+
                 #common_methods
             }
             impl #ig Default for Expectation #tg #wc
@@ -2172,18 +2730,31 @@ impl<'a> ToTokens for RefExpectations<'a> {
             #common_methods
             impl #ig Expectations #tg #wc {
                 /// Simulate calling the real method.  Every current expectation
-                /// will be checked in FIFO order and the first one with
-                /// matching arguments will be used.
+                /// will be checked in priority order, then FIFO order, and the
+                /// first one with matching arguments will be used.
                 #v fn call #lg (&self, #(#argnames: #argty, )* )
                     -> Option<#output>
                 {
-                    self.0.iter()
-                        .find(|__mockall_e|
+                    // Try each priority level in turn, highest first, without
+                    // allocating.  Within a level, expectations are FIFO.
+                    let mut __mockall_p = self.0.iter()
+                        .map(|__mockall_e| __mockall_e.common.priority)
+                        .max();
+                    while let Some(__mockall_pri) = __mockall_p {
+                        if let Some(__mockall_e) = self.0.iter()
+                            .find(|__mockall_e|
+                              __mockall_e.common.priority == __mockall_pri &&
                               __mockall_e.matches(#(#predexprs, )*) &&
                               (!__mockall_e.is_done() || self.0.len() == 1))
-                        .map(move |__mockall_e|
-                             __mockall_e.call(#(#argnames),*)
-                        )
+                        {
+                            return Some(__mockall_e.call(#(#argnames),*));
+                        }
+                        __mockall_p = self.0.iter()
+                            .map(|__mockall_e| __mockall_e.common.priority)
+                            .filter(|__mockall_q| *__mockall_q < __mockall_pri)
+                            .max();
+                    }
+                    None
                 }
 
             }
@@ -2210,19 +2781,33 @@ impl<'a> ToTokens for RefMutExpectations<'a> {
             #common_methods
             impl #ig Expectations #tg #wc {
                 /// Simulate calling the real method.  Every current expectation
-                /// will be checked in FIFO order and the first one with
-                /// matching arguments will be used.
+                /// will be checked in priority order, then FIFO order, and the
+                /// first one with matching arguments will be used.
                 #v fn call_mut #lg (&mut self, #(#argnames: #argty, )* )
                     -> Option<#output>
                 {
                     let __mockall_n = self.0.len();
-                    self.0.iter_mut()
-                        .find(|__mockall_e|
+                    // Try each priority level in turn, highest first, without
+                    // allocating.  Within a level, expectations are FIFO.
+                    let mut __mockall_p = self.0.iter()
+                        .map(|__mockall_e| __mockall_e.common.priority)
+                        .max();
+                    while let Some(__mockall_pri) = __mockall_p {
+                        if let Some(__mockall_i) = self.0.iter()
+                            .position(|__mockall_e|
+                              __mockall_e.common.priority == __mockall_pri &&
                               __mockall_e.matches(#(#predexprs, )*) &&
                               (!__mockall_e.is_done() || __mockall_n == 1))
-                        .map(move |__mockall_e|
-                             __mockall_e.call_mut(#(#argnames, )*)
-                        )
+                        {
+                            return Some(self.0[__mockall_i]
+                                        .call_mut(#(#argnames, )*));
+                        }
+                        __mockall_p = self.0.iter()
+                            .map(|__mockall_e| __mockall_e.common.priority)
+                            .filter(|__mockall_q| *__mockall_q < __mockall_pri)
+                            .max();
+                    }
+                    None
                 }
 
             }
@@ -2249,18 +2834,31 @@ impl<'a> ToTokens for StaticExpectations<'a> {
             #common_methods
             impl #ig Expectations #tg #wc {
                 /// Simulate calling the real method.  Every current expectation
-                /// will be checked in FIFO order and the first one with
-                /// matching arguments will be used.
+                /// will be checked in priority order, then FIFO order, and the
+                /// first one with matching arguments will be used.
                 #v fn call #lg (&self, #(#argnames: #argty, )* )
                     -> Option<#output>
                 {
-                    self.0.iter()
-                        .find(|__mockall_e|
+                    // Try each priority level in turn, highest first, without
+                    // allocating.  Within a level, expectations are FIFO.
+                    let mut __mockall_p = self.0.iter()
+                        .map(|__mockall_e| __mockall_e.common.priority)
+                        .max();
+                    while let Some(__mockall_pri) = __mockall_p {
+                        if let Some(__mockall_e) = self.0.iter()
+                            .find(|__mockall_e|
+                              __mockall_e.common.priority == __mockall_pri &&
                               __mockall_e.matches(#(#predexprs, )*) &&
                               (!__mockall_e.is_done() || self.0.len() == 1))
-                        .map(move |__mockall_e|
-                             __mockall_e.call(#(#argnames, )*)
-                        )
+                        {
+                            return Some(__mockall_e.call(#(#argnames, )*));
+                        }
+                        __mockall_p = self.0.iter()
+                            .map(|__mockall_e| __mockall_e.common.priority)
+                            .filter(|__mockall_q| *__mockall_q < __mockall_pri)
+                            .max();
+                    }
+                    None
                 }
 
             }
@@ -2294,18 +2892,39 @@ impl<'a> ToTokens for GenericExpectations<'a> {
                                Box<dyn ::mockall::AnyExpectations>>
             }
             impl GenericExpectations {
-                /// Verify that all current expectations are satisfied and clear
-                /// them.  This applies to all sets of generic parameters!
-                #v fn checkpoint(&mut self) ->
-                    std::collections::hash_map::Drain<::mockall::Key,
-                               Box<dyn ::mockall::AnyExpectations>>
+                /// Clear all current expectations.  They will be verified when
+                /// the returned object drops.  This applies to all sets of
+                /// generic parameters!
+                #v fn checkpoint(&mut self) -> Self
                 {
-                    self.store.drain()
+                    GenericExpectations{store: mem::take(&mut self.store)}
                 }
 
                 #v fn new() -> Self {
                     Self::default()
                 }
+
+                /// Describe every expectation that hasn't yet been called as
+                /// many times as required, for all sets of generic parameters.
+                #v fn unsatisfied(&self)
+                    -> ::std::vec::Vec<::std::string::String>
+                {
+                    self.store.values()
+                        .flat_map(|__mockall_e| __mockall_e.unsatisfied())
+                        .collect()
+                }
+            }
+            impl Drop for GenericExpectations {
+                /// Verify that every expectation has been satisfied, reporting
+                /// all of the ones that haven't been.
+                fn drop(&mut self) {
+                    if !::std::thread::panicking() {
+                        let __mockall_msgs = self.unsatisfied();
+                        if !__mockall_msgs.is_empty() {
+                            panic!("{}", __mockall_msgs.join("\n"));
+                        }
+                    }
+                }
             }
             #ge
         ).to_tokens(tokens);
@@ -2345,7 +2964,13 @@ impl<'a> ToTokens for StaticGenericExpectations<'a> {
              format_ident!("downcast_ref"))
         };
         quote!(
-            impl #ig ::mockall::AnyExpectations for Expectations #tg #any_wc {}
+            impl #ig ::mockall::AnyExpectations for Expectations #tg #any_wc {
+                fn unsatisfied(&self)
+                    -> ::std::vec::Vec<::std::string::String>
+                {
+                    Expectations::unsatisfied(self)
+                }
+            }
             impl GenericExpectations {
                 /// Simulating calling the real method.
                 #v fn #call #ig (#self_, #(#argnames: #argty, )* )
@@ -84,10 +84,12 @@ impl Methods {
             .all(|meth| meth.is_static())
     }
 
-    fn checkpoints(&self) -> Vec<impl ToTokens> {
+    /// Return fragments of code that collect each method's unsatisfied
+    /// expectations into `__mockall_msgs`
+    fn unsatisfied(&self) -> Vec<impl ToTokens> {
         self.0.iter()
             .filter(|meth| !meth.is_static())
-            .map(|meth| meth.checkpoint())
+            .map(|meth| meth.unsatisfied())
             .collect::<Vec<_>>()
     }
 
@@ -254,7 +256,7 @@ impl ToTokens for MockItemStruct {
             .filter(|meth| !meth.is_static())
             .map(|meth| meth.expect(modname, None))
             .collect::<Vec<_>>();
-        let method_checkpoints = self.methods.checkpoints();
+        let method_unsatisfied = self.methods.unsatisfied();
         let new_method = self.new_method();
         let priv_mods = self.methods.priv_mods();
         let substructs = unique_trait_iter(self.traits.iter())
@@ -270,7 +272,7 @@ impl ToTokens for MockItemStruct {
                     name: format_ident!("{}_{}", &self.name, trait_.ss_name()),
                 }
             }).collect::<Vec<_>>();
-        let substruct_expectations = substructs.iter()
+        let substruct_unsatisfied = substructs.iter()
             .filter(|ss| !ss.all_static())
             .map(|ss| {
                 let attrs = AttrFormatter::new(&ss.attrs)
@@ -278,8 +280,39 @@ impl ToTokens for MockItemStruct {
                     .doc(false)
                     .format();
                 let fieldname = &ss.fieldname;
-                quote!(#(#attrs)* self.#fieldname.checkpoint();)
+                quote!(#(#attrs)*
+                       __mockall_msgs.extend(self.#fieldname.unsatisfied());)
             }).collect::<Vec<_>>();
+        let has_expectations = !substruct_unsatisfied.is_empty() ||
+            !method_unsatisfied.is_empty();
+        let drop_impl = if has_expectations {
+            quote!(
+                impl #ig Drop for #struct_name #tg #wc {
+                    /// Verify that every expectation of every method has been
+                    /// satisfied, reporting all of the ones that haven't been.
+                    fn drop(&mut self) {
+                        if !::std::thread::panicking() {
+                            let mut __mockall_msgs =
+                                ::std::vec::Vec::<::std::string::String>::new();
+                            #(#substruct_unsatisfied)*
+                            #(#method_unsatisfied)*
+                            if !__mockall_msgs.is_empty() {
+                                panic!("{}", __mockall_msgs.join("\n"));
+                            }
+                        }
+                    }
+                }
+            )
+        } else {
+            // Static methods are verified by their Contexts instead
+            TokenStream::new()
+        };
+        let checkpoint_body = if has_expectations {
+            // The old expectations are verified when they drop
+            quote!(drop(::std::mem::take(self));)
+        } else {
+            TokenStream::new()
+        };
         let mut field_definitions = substructs.iter()
             .filter(|ss| !ss.all_static())
             .map(|ss| {
@@ -328,6 +361,7 @@ impl ToTokens for MockItemStruct {
                 #(#field_definitions),*
             }
             #debug_impl
+            #drop_impl
             impl #ig ::std::default::Default for #struct_name #tg #wc {
                 #[allow(clippy::default_trait_access)]
                 fn default() -> Self {
@@ -345,8 +379,7 @@ impl ToTokens for MockItemStruct {
                 /// Validate that all current expectations for all methods have
                 /// been satisfied, and discard them.
                 pub fn checkpoint(&mut self) {
-                    #(#substruct_expectations)*
-                    #(#method_checkpoints)*
+                    #checkpoint_body
                 }
                 #new_method
             }
@@ -391,7 +424,7 @@ impl ToTokens for MockItemTraitImpl {
         let struct_name = &self.name;
         let (ig, tg, wc) = self.generics.split_for_impl();
         let modname = &self.modname;
-        let method_checkpoints = self.methods.checkpoints();
+        let method_unsatisfied = self.methods.unsatisfied();
         let mut default_inits = self.methods.default_inits();
         default_inits.extend(self.phantom_default_inits());
         let mut field_definitions = self.methods.field_definitions(modname);
@@ -423,10 +456,15 @@ impl ToTokens for MockItemTraitImpl {
             }
             #(#attrs)*
             impl #ig #struct_name #tg #wc {
-                /// Validate that all current expectations for all methods have
-                /// been satisfied, and discard them.
-                pub fn checkpoint(&mut self) {
-                    #(#method_checkpoints)*
+                /// Describe every current expectation, for any method, that
+                /// hasn't been satisfied yet.
+                pub fn unsatisfied(&self)
+                    -> ::std::vec::Vec<::std::string::String>
+                {
+                    #[allow(unused_mut)]
+                    let mut __mockall_msgs = ::std::vec::Vec::new();
+                    #(#method_unsatisfied)*
+                    __mockall_msgs
                 }
             }
         ).to_tokens(tokens);
@@ -1,5 +1,5 @@
 // vim: tw=80
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, format_ident, quote};
 use std::{
    collections::hash_map::DefaultHasher,
@@ -21,6 +21,8 @@ pub(crate) struct MockTrait {
     pub consts: Vec<ImplItemConst>,
     pub generics: Generics,
     pub methods: Vec<MockFunction>,
+    /// Methods that aren't mocked, but are implemented as-is
+    stubs: Vec<TokenStream>,
     /// Internally-used name of the trait used.
     pub ss_name: Ident,
     /// Fully-qualified name of the trait
@@ -66,6 +68,7 @@ impl MockTrait {
     {
         let mut consts = Vec::new();
         let mut methods = Vec::new();
+        let mut stubs = Vec::new();
         let mut types = Vec::new();
         let trait_path = if let Some((_, path, _)) = impl_.trait_ {
             path
@@ -103,6 +106,10 @@ impl MockTrait {
                 ImplItem::Type(iit) => {
                     types.push(iit);
                 },
+                ImplItem::Verbatim(ts) => {
+                    // Created by mockable_trait for #[skip] methods
+                    stubs.push(ts);
+                },
                 _ => {
                     compile_error(ii.span(),
                     "This impl item is not yet supported by MockAll");
@@ -114,6 +121,7 @@ impl MockTrait {
             consts,
             generics: impl_.generics,
             methods,
+            stubs,
             ss_name,
             trait_path,
             self_path,
@@ -157,6 +165,7 @@ impl MockTrait {
             }).collect::<Vec<_>>();
         let trait_path = &self.trait_path;
         let self_path = &self.self_path;
+        let stubs = &self.stubs;
         let types = &self.types;
         let unsafety = &self.unsafety;
         quote!(
@@ -165,6 +174,7 @@ impl MockTrait {
                 #(#consts)*
                 #(#types)*
                 #(#calls)*
+                #(#stubs)*
             }
             #(#impl_attrs)*
             impl #ig #self_path #wc {
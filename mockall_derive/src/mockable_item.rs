@@ -28,6 +28,12 @@ pub(crate) enum MockableItem {
 
 impl From<(Attrs, Item)> for MockableItem {
     fn from((attrs, item): (Attrs, Item)) -> MockableItem {
+        if let Some(structname) = &attrs.structname {
+            if !matches!(item, Item::Trait(_)) {
+                compile_error(structname.span(),
+                    "struct name attributes are only supported for traits");
+            }
+        }
         match item {
             Item::Impl(item_impl) =>
                 MockableItem::Struct(MockableStruct::from(item_impl)),
@@ -104,7 +110,7 @@ impl From<(Attrs, ItemForeignMod)> for MockableModule {
 
                         for arg in sig.inputs.iter_mut() {
                             if let FnArg::Typed(pt) = arg {
-                                *pt.ty = supersuperfy(&*pt.ty, 1);
+                                *pt.ty = supersuperfy(&pt.ty, 1);
                             }
                         }
                         if let ReturnType::Type(_, ty) = &mut sig.output {
@@ -145,7 +145,7 @@ fn mockable_method(meth: &mut ImplItemMethod, name: &Ident, generics: &Generics)
         deselfify(ty, name, generics);
         deanonymize(ty);
     }
-    sanity_check_sig(&meth.sig);
+    sanity_check_sig(&meth.sig, &meth.attrs);
 }
 
 /// Performs transformations on the method to make it mockable
@@ -161,7 +161,22 @@ fn mockable_trait_method(
         deselfify(ty, name, generics);
         deanonymize(ty);
     }
-    sanity_check_sig(&meth.sig);
+    sanity_check_sig(&meth.sig, &meth.attrs);
+}
+
+/// Should this trait item keep its default implementation instead of being
+/// mocked?
+fn uses_default(ti: &TraitItem) -> bool {
+    if let TraitItem::Method(tim) = ti {
+        let use_default = has_mockall_attr(&tim.attrs, "use_default");
+        if use_default && tim.default.is_none() {
+            compile_error(tim.sig.span(),
+                "#[use_default] methods must have a default implementation");
+        }
+        use_default
+    } else {
+        false
+    }
 }
 
 /// Generates a mockable item impl from a trait method definition
@@ -169,8 +184,22 @@ fn mockable_trait(trait_: ItemTrait, name: &Ident, generics: &Generics)
     -> ItemImpl
 {
     let items = trait_.items.into_iter()
+    .filter(|ti| !uses_default(ti))
     .map(|ti| {
         match ti {
+            TraitItem::Method(tim) if has_mockall_attr(&tim.attrs, "skip") => {
+                // Implement the method with a stub, using the original
+                // signature, since it might not be mockable.
+                let sig = &tim.sig;
+                let msg = format!("{}::{} is not mocked, because it is marked #[skip]",
+                                  name, sig.ident);
+                ImplItem::Verbatim(quote!(
+                    #[allow(unused_variables)]
+                    #sig {
+                        panic!(#msg)
+                    }
+                ))
+            },
             TraitItem::Method(mut tim) => {
                 mockable_trait_method(&mut tim, name, generics);
                 ImplItem::Method(tim2iim(tim, &Visibility::Inherited))
@@ -216,7 +245,11 @@ fn mockable_trait(trait_: ItemTrait, name: &Ident, generics: &Generics)
     }
 }
 
-fn sanity_check_sig(sig: &Signature) {
+fn sanity_check_sig(sig: &Signature, attrs: &[Attribute]) {
+    if has_mockall_attr(attrs, "concretize") {
+        // Any "impl trait" arguments will become trait objects
+        return;
+    }
     for arg in sig.inputs.iter() {
         if let FnArg::Typed(pt) = arg {
             if let Type::ImplTrait(it) = pt.ty.as_ref() {
@@ -309,12 +342,12 @@ impl MockableStruct {
         .any(|attr| {
             if let Ok(Meta::List(ml)) = attr.parse_meta() {
                 let i = ml.path.get_ident();
-                if i.map_or(false, |i| *i == "derive") {
+                if i.is_some_and(|i| *i == "derive") {
                     ml.nested.iter()
                     .any(|nm| {
                         if let NestedMeta::Meta(m) = nm {
                             let i = m.path().get_ident();
-                            i.map_or(false, |i| *i == "Debug")
+                            i.is_some_and(|i| *i == "Debug")
                         } else {
                             false
                         }
@@ -332,10 +365,12 @@ impl MockableStruct {
 impl From<(Attrs, ItemTrait)> for MockableStruct {
     fn from((attrs, item_trait): (Attrs, ItemTrait)) -> MockableStruct {
         let trait_ = attrs.substitute_trait(&item_trait);
+        let structname = attrs.structname;
         let mut attrs = trait_.attrs.clone();
         attrs.push(derive_debug());
         let vis = trait_.vis.clone();
-        let name = gen_mock_ident(&trait_.ident);
+        let name = structname
+            .unwrap_or_else(|| gen_mock_ident(&trait_.ident));
         let generics = trait_.generics.clone();
         let impls = vec![mockable_trait(trait_, &name, &generics)];
         MockableStruct {
@@ -371,6 +406,14 @@ impl From<ItemImpl> for MockableStruct {
         let pub_token = Token![pub](Span::call_site());
         let vis = Visibility::Public(VisPublic{pub_token});
         let mut impls = Vec::new();
+        for item in item_impl.items.iter() {
+            if let ImplItem::Method(meth) = item {
+                if has_mockall_attr(&meth.attrs, "skip") {
+                    compile_error(meth.sig.span(),
+                        "#[skip] is only supported on methods of traits");
+                }
+            }
+        }
         if let Some((bang, _path, _)) = &item_impl.trait_ {
             if bang.is_some() {
                 compile_error(bang.span(), "Unsupported by automock");
@@ -614,7 +657,7 @@ mod sanity_check_sig {
         let meth: ImplItemMethod = parse2(quote!(
             fn foo(&self, x: impl SomeTrait);
         )).unwrap();
-        sanity_check_sig(&meth.sig);
+        sanity_check_sig(&meth.sig, &meth.attrs);
     }
 }
 }